@@ -0,0 +1,676 @@
+//! Derive macros for the marker traits in `briny::traits` and for
+//! `briny::layout::TypeLayout`.
+//!
+//! Every derive checks the layout it promises before emitting the `unsafe impl`:
+//! the `repr` must be defined, every field must implement the derived trait, and
+//! [`Pod`](macro@Pod) additionally refuses types whose fields do not add up to
+//! `size_of::<Self>()` (i.e. types with padding).
+//!
+//! These macros are re-exported by `briny` behind the `derive` feature and are
+//! not meant to be depended on directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Attribute, Data, DeriveInput, Fields,
+    Generics, Ident, LitInt, Path, Type,
+};
+
+/// The `repr` attributes found on a type.
+#[derive(Default)]
+struct Repr {
+    c: bool,
+    transparent: bool,
+    packed: Option<u64>,
+    align: Option<u64>,
+    int: Option<Ident>,
+}
+
+impl Repr {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut repr = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+            attr.parse_nested_meta(|meta| {
+                let Some(ident) = meta.path.get_ident() else {
+                    return Err(meta.error("unrecognized repr"));
+                };
+                match ident.to_string().as_str() {
+                    "C" => repr.c = true,
+                    "transparent" => repr.transparent = true,
+                    "packed" => {
+                        let mut align = 1;
+                        if meta.input.peek(syn::token::Paren) {
+                            let content;
+                            syn::parenthesized!(content in meta.input);
+                            align = content.parse::<LitInt>()?.base10_parse()?;
+                        }
+                        repr.packed = Some(align);
+                    }
+                    "align" => {
+                        let content;
+                        syn::parenthesized!(content in meta.input);
+                        repr.align = Some(content.parse::<LitInt>()?.base10_parse()?);
+                    }
+                    "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128"
+                    | "i128" | "usize" | "isize" => repr.int = Some(ident.clone()),
+                    _ => return Err(meta.error("unrecognized repr")),
+                }
+                Ok(())
+            })?;
+        }
+        Ok(repr)
+    }
+
+    /// Whether the layout of a struct with this repr is defined at all.
+    const fn is_stable_struct(&self) -> bool {
+        self.c || self.transparent
+    }
+
+    /// Whether the layout of a fieldless enum with this repr is defined at all.
+    const fn is_stable_enum(&self) -> bool {
+        self.c || self.int.is_some()
+    }
+
+    /// Whether an enum with this repr is exactly one byte wide.
+    fn is_byte_enum(&self) -> bool {
+        self.int.as_ref().is_some_and(|int| int == "u8" || int == "i8")
+    }
+}
+
+/// Collects the field types of every variant (or the struct itself).
+fn field_types(data: &Data) -> Vec<&Type> {
+    match data {
+        Data::Struct(data) => data.fields.iter().map(|field| &field.ty).collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().map(|field| &field.ty))
+            .collect(),
+        Data::Union(data) => data.fields.named.iter().map(|field| &field.ty).collect(),
+    }
+}
+
+/// Adds `trait_path` as a bound on every type parameter.
+fn bounded_generics(generics: &Generics, trait_path: &Path) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#trait_path));
+    }
+    generics
+}
+
+/// Emits the `unsafe impl` together with a compile-time check that every field
+/// implements the same trait.
+fn expand_impl(input: &DeriveInput, trait_path: &Path, checks: &TokenStream2) -> TokenStream2 {
+    let name = &input.ident;
+    let generics = bounded_generics(&input.generics, trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let tys = field_types(&input.data);
+
+    quote! {
+        const _: () = {
+            #[allow(dead_code)]
+            fn __assert_fields #impl_generics () #where_clause {
+                fn __assert_impl<__F: ?::core::marker::Sized + #trait_path>() {}
+                #( __assert_impl::<#tys>(); )*
+            }
+        };
+
+        #checks
+
+        unsafe impl #impl_generics #trait_path for #name #ty_generics #where_clause {}
+    }
+}
+
+/// Rejects anything but fieldless enums.
+fn require_fieldless(input: &DeriveInput, trait_name: &str) -> syn::Result<()> {
+    if let Data::Enum(data) = &input.data {
+        if let Some(variant) = data
+            .variants
+            .iter()
+            .find(|variant| !matches!(variant.fields, Fields::Unit))
+        {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!("`{trait_name}` can only be derived for enums without fields"),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Rejects unions, whose active field cannot be known.
+fn reject_union(input: &DeriveInput, trait_name: &str) -> syn::Result<()> {
+    if matches!(input.data, Data::Union(_)) {
+        return Err(syn::Error::new(
+            input.span(),
+            format!("`{trait_name}` cannot be derived for unions"),
+        ));
+    }
+    Ok(())
+}
+
+/// Requires a defined layout: `repr(C)`/`repr(transparent)` for structs and
+/// `repr(C)`/`repr(<int>)` for fieldless enums.
+fn require_stable_repr(input: &DeriveInput, repr: &Repr, trait_name: &str) -> syn::Result<()> {
+    reject_union(input, trait_name)?;
+    require_fieldless(input, trait_name)?;
+    let stable = match input.data {
+        Data::Enum(_) => repr.is_stable_enum(),
+        _ => repr.is_stable_struct(),
+    };
+    if !stable {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            format!(
+                "`{trait_name}` requires `#[repr(C)]` or `#[repr(transparent)]` (or an integer repr for enums)"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn derive_pod_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let repr = Repr::parse(&input.attrs)?;
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`Pod` can only be derived for structs",
+        ));
+    };
+    if !repr.is_stable_struct() {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`Pod` requires `#[repr(C)]` or `#[repr(transparent)]`",
+        ));
+    }
+
+    let name = &input.ident;
+    let checks = if repr.transparent {
+        // a transparent wrapper has exactly the layout of its only non-ZST field
+        TokenStream2::new()
+    } else if input.generics.type_params().next().is_some() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`Pod` cannot verify the padding of a generic `#[repr(C)]` struct",
+        ));
+    } else {
+        let tys = data.fields.iter().map(|field| &field.ty);
+        let msg = format!("`{name}` contains padding and cannot derive `Pod`");
+        quote! {
+            const _: () = ::core::assert!(
+                ::core::mem::size_of::<#name>() == 0 #( + ::core::mem::size_of::<#tys>() )*,
+                #msg
+            );
+        }
+    };
+
+    Ok(expand_impl(
+        input,
+        &parse_quote!(::briny::traits::Pod),
+        &checks,
+    ))
+}
+
+fn derive_stable_layout_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let repr = Repr::parse(&input.attrs)?;
+    require_stable_repr(input, &repr, "StableLayout")?;
+    Ok(expand_impl(
+        input,
+        &parse_quote!(::briny::traits::StableLayout),
+        &TokenStream2::new(),
+    ))
+}
+
+fn derive_raw_convert_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let repr = Repr::parse(&input.attrs)?;
+    require_stable_repr(input, &repr, "RawConvert")?;
+    Ok(expand_impl(
+        input,
+        &parse_quote!(::briny::traits::RawConvert),
+        &TokenStream2::new(),
+    ))
+}
+
+fn derive_unaligned_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let repr = Repr::parse(&input.attrs)?;
+    reject_union(input, "Unaligned")?;
+    require_fieldless(input, "Unaligned")?;
+
+    let name = &input.ident;
+    let trait_path: Path = parse_quote!(::briny::traits::Unaligned);
+    if repr.align.is_some_and(|align| align > 1) {
+        return Err(syn::Error::new(
+            name.span(),
+            "`Unaligned` cannot be derived for types with `#[repr(align(N))]`",
+        ));
+    }
+
+    let msg = format!("`{name}` is not aligned to one byte and cannot derive `Unaligned`");
+    let generic = !input.generics.params.is_empty();
+    let checks = if generic {
+        TokenStream2::new()
+    } else {
+        quote! {
+            const _: () = ::core::assert!(::core::mem::align_of::<#name>() == 1, #msg);
+        }
+    };
+
+    match (&input.data, repr.packed) {
+        (Data::Enum(_), _) if !repr.is_byte_enum() => {
+            return Err(syn::Error::new(
+                name.span(),
+                "`Unaligned` requires `#[repr(u8)]` or `#[repr(i8)]` on enums",
+            ));
+        }
+        (Data::Struct(_), Some(align)) if align != 1 => {
+            return Err(syn::Error::new(
+                name.span(),
+                "`Unaligned` requires `#[repr(packed)]` to pack to one byte",
+            ));
+        }
+        (Data::Struct(_), Some(_)) => {
+            // packing to one byte makes the field alignments irrelevant
+            let generics = bounded_generics(&input.generics, &trait_path);
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            return Ok(quote! {
+                #checks
+
+                unsafe impl #impl_generics #trait_path for #name #ty_generics #where_clause {}
+            });
+        }
+        (Data::Struct(_), None) if !repr.is_stable_struct() => {
+            return Err(syn::Error::new(
+                name.span(),
+                "`Unaligned` requires `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(packed)]`",
+            ));
+        }
+        _ => {}
+    }
+
+    if generic {
+        // the alignment of a generic type depends on its parameters, so the impl
+        // only holds where every field is itself `Unaligned`
+        let mut generics = bounded_generics(&input.generics, &trait_path);
+        let predicates = &mut generics.make_where_clause().predicates;
+        for ty in field_types(&input.data) {
+            predicates.push(parse_quote!(#ty: #trait_path));
+        }
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        return Ok(quote! {
+            unsafe impl #impl_generics #trait_path for #name #ty_generics #where_clause {}
+        });
+    }
+
+    Ok(expand_impl(input, &trait_path, &checks))
+}
+
+fn derive_interior_immutable_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    reject_union(input, "InteriorImmutable")?;
+    Ok(expand_impl(
+        input,
+        &parse_quote!(::briny::traits::InteriorImmutable),
+        &TokenStream2::new(),
+    ))
+}
+
+fn derive_checked_bit_pattern_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let repr = Repr::parse(&input.attrs)?;
+    require_stable_repr(input, &repr, "CheckedBitPattern")?;
+
+    let name = &input.ident;
+    let trait_path: Path = parse_quote!(::briny::traits::CheckedBitPattern);
+    let generics = bounded_generics(&input.generics, &trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Enum(data) => {
+            let Some(int) = &repr.int else {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "`CheckedBitPattern` requires an integer repr on enums",
+                ));
+            };
+            let variants = data.variants.iter().map(|variant| &variant.ident);
+            quote! {
+                let mut raw = [0u8; ::core::mem::size_of::<#int>()];
+                raw.copy_from_slice(bytes);
+                let discriminant = #int::from_ne_bytes(raw);
+                false #( || discriminant == Self::#variants as #int )*
+            }
+        }
+        Data::Struct(data) => {
+            let checks = data.fields.iter().enumerate().map(|(i, field)| {
+                let ty = &field.ty;
+                let member = field.ident.as_ref().map_or_else(
+                    || {
+                        let index = syn::Index::from(i);
+                        quote!(#index)
+                    },
+                    |ident| quote!(#ident),
+                );
+                quote! {
+                    <#ty as #trait_path>::is_valid_bit_pattern({
+                        let offset = ::core::mem::offset_of!(Self, #member);
+                        &bytes[offset..offset + ::core::mem::size_of::<#ty>()]
+                    })
+                }
+            });
+            quote! {
+                true #( && #checks )*
+            }
+        }
+        Data::Union(_) => unreachable!("unions are rejected above"),
+    };
+
+    Ok(quote! {
+        unsafe impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            #[inline]
+            fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+                #body
+            }
+        }
+    })
+}
+
+fn derive_type_layout_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let repr = Repr::parse(&input.attrs)?;
+    require_stable_repr(input, &repr, "TypeLayout")?;
+
+    let name = &input.ident;
+    let trait_path: Path = parse_quote!(::briny::layout::TypeLayout);
+    let generics = bounded_generics(&input.generics, &trait_path);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let fields: Vec<_> = match &input.data {
+        Data::Struct(data) => data.fields.iter().enumerate().collect(),
+        _ => Vec::new(),
+    };
+    let tys: Vec<_> = fields.iter().map(|(_, field)| &field.ty).collect();
+    let layouts = fields.iter().map(|(i, field)| {
+        let ty = &field.ty;
+        let (member, field_name) = field.ident.as_ref().map_or_else(
+            || {
+                let index = syn::Index::from(*i);
+                (quote!(#index), i.to_string())
+            },
+            |ident| (quote!(#ident), ident.to_string()),
+        );
+        quote! {
+            ::briny::layout::FieldLayout::of::<#ty>(
+                #field_name,
+                ::core::stringify!(#ty),
+                ::core::mem::offset_of!(Self, #member),
+            )
+        }
+    });
+
+    let variants = match &input.data {
+        Data::Enum(data) => {
+            let variants = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                let variant_name = ident.to_string();
+                quote! {
+                    ::briny::layout::VariantLayout::new(#variant_name, Self::#ident as i128)
+                }
+            });
+            quote! {
+                const VARIANTS: &'static [::briny::layout::VariantLayout] = &[#( #variants ),*];
+            }
+        }
+        _ => TokenStream2::new(),
+    };
+
+    Ok(quote! {
+        impl #impl_generics #trait_path for #name #ty_generics #where_clause {
+            const FIELDS: &'static [::briny::layout::FieldLayout] = &[#( #layouts ),*];
+
+            #variants
+
+            #[inline]
+            fn has_padding() -> bool {
+                ::briny::layout::has_padding(
+                    <Self as #trait_path>::SIZE,
+                    <Self as #trait_path>::FIELDS,
+                ) #( || <#tys as #trait_path>::has_padding() )*
+            }
+        }
+    })
+}
+
+fn expand(result: syn::Result<TokenStream2>) -> TokenStream {
+    result.unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derives `briny::traits::Pod`.
+///
+/// The struct must be `#[repr(C)]` or `#[repr(transparent)]`, every field must
+/// be `Pod`, and the fields must cover the whole struct without padding.
+/// `StableLayout` and `RawConvert` must be derived (or implemented) as well.
+///
+/// ```
+/// use briny::traits::{Pod, RawConvert, StableLayout};
+///
+/// #[derive(Clone, Copy, Pod, StableLayout, RawConvert)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+/// }
+/// ```
+///
+/// Padding is rejected at compile time:
+///
+/// ```compile_fail
+/// use briny::traits::{Pod, RawConvert, StableLayout};
+///
+/// #[derive(Clone, Copy, Pod, StableLayout, RawConvert)]
+/// #[repr(C)]
+/// struct Padded {
+///     tag: u8,
+///     value: u32,
+/// }
+/// ```
+///
+/// So are types without a defined layout:
+///
+/// ```compile_fail
+/// use briny::traits::{Pod, RawConvert, StableLayout};
+///
+/// #[derive(Clone, Copy, Pod, StableLayout, RawConvert)]
+/// struct Header {
+///     magic: u32,
+///     len: u32,
+/// }
+/// ```
+///
+/// And fields that are not `Pod` themselves:
+///
+/// ```compile_fail
+/// use briny::traits::{Pod, RawConvert, StableLayout};
+///
+/// #[derive(Clone, Copy, Pod, StableLayout, RawConvert)]
+/// #[repr(C)]
+/// struct Flags {
+///     set: bool,
+///     rest: [u8; 3],
+/// }
+/// ```
+#[proc_macro_derive(Pod)]
+pub fn derive_pod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(derive_pod_impl(&input))
+}
+
+/// Derives `briny::traits::StableLayout`.
+///
+/// Structs must be `#[repr(C)]` or `#[repr(transparent)]` with `StableLayout`
+/// fields; enums must be fieldless with `#[repr(C)]` or an integer repr.
+///
+/// ```compile_fail
+/// use briny::traits::StableLayout;
+///
+/// #[derive(StableLayout)]
+/// enum Mode {
+///     Read,
+///     Write,
+/// }
+/// ```
+#[proc_macro_derive(StableLayout)]
+pub fn derive_stable_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(derive_stable_layout_impl(&input))
+}
+
+/// Derives `briny::traits::RawConvert`.
+///
+/// The same layout requirements as [`StableLayout`](macro@StableLayout) apply,
+/// and every field must be `RawConvert`.
+///
+/// ```compile_fail
+/// use briny::traits::RawConvert;
+///
+/// #[derive(RawConvert)]
+/// #[repr(C)]
+/// struct Shared {
+///     value: core::sync::atomic::AtomicU32,
+/// }
+/// ```
+#[proc_macro_derive(RawConvert)]
+pub fn derive_raw_convert(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(derive_raw_convert_impl(&input))
+}
+
+/// Derives `briny::traits::CheckedBitPattern`.
+///
+/// Structs validate each field at its offset, so every field must implement
+/// `CheckedBitPattern`; fieldless enums need an integer repr and accept exactly
+/// their declared discriminants. `StableLayout` and `RawConvert` must be derived
+/// (or implemented) as well.
+///
+/// ```
+/// use briny::traits::{CheckedBitPattern, RawConvert, StableLayout};
+///
+/// #[derive(Clone, Copy, CheckedBitPattern, StableLayout, RawConvert)]
+/// #[repr(u8)]
+/// enum Kind {
+///     Data = 1,
+///     Ack = 2,
+/// }
+///
+/// assert!(Kind::is_valid_bit_pattern(&[2]));
+/// assert!(!Kind::is_valid_bit_pattern(&[3]));
+/// ```
+#[proc_macro_derive(CheckedBitPattern)]
+pub fn derive_checked_bit_pattern(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(derive_checked_bit_pattern_impl(&input))
+}
+
+/// Derives `briny::traits::Unaligned`.
+///
+/// Structs must either be `#[repr(packed)]`, or `#[repr(C)]`/`#[repr(transparent)]`
+/// with only `Unaligned` fields; enums must be `#[repr(u8)]` or `#[repr(i8)]`.
+///
+/// ```compile_fail
+/// use briny::traits::Unaligned;
+///
+/// #[derive(Unaligned)]
+/// #[repr(C)]
+/// struct Word {
+///     value: u32,
+/// }
+/// ```
+///
+/// An explicit alignment is rejected even when the fields are unaligned:
+///
+/// ```compile_fail
+/// use briny::traits::Unaligned;
+///
+/// #[derive(Unaligned)]
+/// #[repr(C, align(4))]
+/// struct Padded<T>(T);
+/// ```
+///
+/// Generic types only implement it where every field is `Unaligned`, so an
+/// aligned field is rejected as well:
+///
+/// ```compile_fail
+/// use briny::traits::Unaligned;
+///
+/// #[derive(Unaligned)]
+/// #[repr(C)]
+/// struct Tagged<T> {
+///     tag: T,
+///     value: u32,
+/// }
+/// ```
+#[proc_macro_derive(Unaligned)]
+pub fn derive_unaligned(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(derive_unaligned_impl(&input))
+}
+
+/// Derives `briny::traits::InteriorImmutable`.
+///
+/// Every field must be `InteriorImmutable`; no `repr` is required.
+///
+/// ```compile_fail
+/// use briny::traits::InteriorImmutable;
+///
+/// #[derive(InteriorImmutable)]
+/// struct Counter {
+///     value: core::cell::Cell<u32>,
+/// }
+/// ```
+#[proc_macro_derive(InteriorImmutable)]
+pub fn derive_interior_immutable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(derive_interior_immutable_impl(&input))
+}
+
+/// Derives `briny::layout::TypeLayout`.
+///
+/// The type must be `#[repr(C)]` or `#[repr(transparent)]` (or a fieldless
+/// enum with a defined repr), and every field must implement `TypeLayout`.
+/// `StableLayout` must be derived (or implemented) as well.
+///
+/// ```
+/// use briny::layout::TypeLayout;
+/// use briny::traits::StableLayout;
+///
+/// #[derive(TypeLayout, StableLayout)]
+/// #[repr(C)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+/// }
+///
+/// assert_eq!(Header::FIELDS.len(), 2);
+/// assert_eq!(Header::FIELDS[1].offset(), 4);
+/// assert!(Header::has_padding());
+/// ```
+///
+/// The derived `LAYOUT_HASH` covers every field's name, offset, size and type
+/// layout, so reordering fields changes it. For enums it covers the name and
+/// discriminant of every variant instead.
+///
+/// Types without a defined layout are rejected:
+///
+/// ```compile_fail
+/// use briny::layout::TypeLayout;
+/// use briny::traits::StableLayout;
+///
+/// #[derive(TypeLayout, StableLayout)]
+/// struct Header {
+///     magic: u32,
+///     len: u16,
+/// }
+/// ```
+#[proc_macro_derive(TypeLayout)]
+pub fn derive_type_layout(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(derive_type_layout_impl(&input))
+}
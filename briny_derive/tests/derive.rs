@@ -0,0 +1,195 @@
+use briny::raw::{cast, from_bytes, to_bytes};
+use briny::traits::{InteriorImmutable, Pod, RawConvert, StableLayout, Unaligned};
+
+#[test]
+fn derived_pod_roundtrip() {
+    #[derive(Copy, Clone, Debug, PartialEq, Pod, StableLayout, RawConvert, InteriorImmutable)]
+    #[repr(C)]
+    struct Header {
+        magic: u32,
+        len: u16,
+        flags: [u8; 2],
+    }
+
+    let header = Header {
+        magic: 0xCAFE_BABE,
+        len: 12,
+        flags: [1, 2],
+    };
+    let bytes = to_bytes(&header);
+    assert_eq!(bytes.len(), 8);
+    assert_eq!(from_bytes::<Header>(bytes).unwrap(), header);
+
+    let raw: [u32; 2] = cast(&header);
+    assert_eq!(raw[0], 0xCAFE_BABE);
+}
+
+#[test]
+fn derived_transparent_generic() {
+    #[derive(Copy, Clone, Debug, PartialEq, Pod, StableLayout, RawConvert)]
+    #[repr(transparent)]
+    struct Wrapper<T>(T);
+
+    let wrapped = Wrapper(0x1234_5678u32);
+    let raw: u32 = cast(&wrapped);
+    assert_eq!(raw, 0x1234_5678);
+}
+
+#[test]
+fn derived_unaligned_layouts() {
+    #[derive(Copy, Clone, Unaligned, StableLayout)]
+    #[repr(C)]
+    struct Bytes {
+        a: u8,
+        b: [i8; 3],
+    }
+
+    #[derive(Copy, Clone, Unaligned)]
+    #[repr(C, packed)]
+    struct Packed {
+        a: u8,
+        b: u64,
+    }
+
+    #[derive(Copy, Clone, Unaligned, StableLayout, RawConvert, InteriorImmutable)]
+    #[repr(u8)]
+    enum Mode {
+        Read = 1,
+        Write = 2,
+    }
+
+    #[derive(Copy, Clone, Unaligned)]
+    #[repr(transparent)]
+    struct Wrapper<T>(T);
+
+    fn assert_unaligned<T: Unaligned>() -> usize {
+        align_of::<T>()
+    }
+
+    assert_eq!(assert_unaligned::<Bytes>(), 1);
+    assert_eq!(assert_unaligned::<Packed>(), 1);
+    assert_eq!(assert_unaligned::<Mode>(), 1);
+    assert_eq!(assert_unaligned::<Wrapper<[u8; 3]>>(), 1);
+    assert_eq!(Mode::Write as u8 - Mode::Read as u8, 1);
+}
+
+#[test]
+fn derived_checked_bit_pattern() {
+    use briny::raw::{try_from_bytes, try_slice_from_bytes};
+    use briny::traits::CheckedBitPattern;
+
+    #[derive(
+        Copy, Clone, Debug, PartialEq, CheckedBitPattern, StableLayout, RawConvert, InteriorImmutable,
+    )]
+    #[repr(u8)]
+    enum Kind {
+        Data = 1,
+        Ack = 2,
+    }
+
+    #[derive(
+        Copy, Clone, Debug, PartialEq, CheckedBitPattern, StableLayout, RawConvert, InteriorImmutable,
+    )]
+    #[repr(C)]
+    struct Frame {
+        kind: Kind,
+        urgent: bool,
+        len: [u8; 2],
+    }
+
+    assert_eq!(try_from_bytes::<Kind>(&[2]).unwrap(), Kind::Ack);
+    assert!(try_from_bytes::<Kind>(&[0]).unwrap_err().is_invalid_bitpattern());
+
+    let frame = try_from_bytes::<Frame>(&[1, 1, 0x10, 0x00]).unwrap();
+    assert_eq!(frame.kind, Kind::Data);
+    assert!(frame.urgent);
+
+    let err = try_slice_from_bytes::<Frame>(&[1, 0, 0, 0, 2, 7, 0, 0]).unwrap_err();
+    assert!(err.is_invalid_bitpattern());
+}
+
+#[test]
+fn derived_type_layout() {
+    use briny::layout::TypeLayout;
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(C)]
+    struct Inner {
+        tag: u8,
+        value: u32,
+    }
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(C)]
+    struct Outer {
+        id: u64,
+        inner: Inner,
+    }
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(transparent)]
+    struct Wrapper<T>([T; 2]);
+
+    let fields = Outer::FIELDS;
+    assert_eq!((Outer::SIZE, Outer::ALIGN), (16, 8));
+    assert_eq!(fields[1].name(), "inner");
+    assert_eq!(fields[1].ty(), "Inner");
+    assert_eq!((fields[1].offset(), fields[1].size()), (8, 8));
+    assert!(Inner::has_padding());
+    assert!(Outer::has_padding());
+
+    assert_eq!(Wrapper::<u16>::FIELDS[0].name(), "0");
+    assert_eq!(Wrapper::<u16>::FIELDS[0].size(), 4);
+    assert!(!Wrapper::<u16>::has_padding());
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(C)]
+    struct Reordered {
+        inner: Inner,
+        id: u64,
+    }
+
+    assert_ne!(Outer::LAYOUT_HASH, Reordered::LAYOUT_HASH);
+    assert_eq!(fields[1].layout_hash(), Inner::LAYOUT_HASH);
+    assert_ne!(Wrapper::<u16>::LAYOUT_HASH, Wrapper::<i16>::LAYOUT_HASH);
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(C)]
+    struct Shared {
+        count: core::sync::atomic::AtomicU32,
+        data: *const u8,
+        marker: core::marker::PhantomData<u64>,
+    }
+
+    assert_eq!(Shared::FIELDS[1].offset(), size_of::<usize>());
+    assert_eq!(Shared::FIELDS[2].size(), 0);
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(u8)]
+    enum Mode {
+        Read = 1,
+        Write = 2,
+    }
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(u8)]
+    enum Swapped {
+        Write = 2,
+        Read = 1,
+    }
+
+    #[derive(TypeLayout, StableLayout)]
+    #[repr(u8)]
+    enum Renumbered {
+        Read = 1,
+        Write = 3,
+    }
+
+    assert_eq!(Mode::VARIANTS[1].name(), "Write");
+    assert_eq!(Mode::VARIANTS[1].discriminant(), Mode::Write as i128);
+    assert_eq!(Mode::Read as u8, 1);
+    assert_ne!(Mode::LAYOUT_HASH, Swapped::LAYOUT_HASH);
+    assert_ne!(Mode::LAYOUT_HASH, Renumbered::LAYOUT_HASH);
+    assert_eq!((Swapped::Write as u8, Swapped::Read as u8), (2, 1));
+    assert_eq!((Renumbered::Read as u8, Renumbered::Write as u8), (1, 3));
+}
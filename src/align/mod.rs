@@ -0,0 +1,301 @@
+//! Wrappers that raise or lower the alignment of a value or byte buffer.
+//!
+//! The fallible casts in [`raw`](crate::raw) reject misaligned input, so bytes
+//! meant to be cast usually need a stronger alignment than `[u8; N]` has.
+//! [`AlignedBytes`] is a byte buffer aligned to one of the markers
+//! [`Align2`]..[`Align4096`], usable on the stack or in a `static`:
+//!
+//! ```
+//! use briny::align::{Align4, AlignedBytes};
+//!
+//! let buf = AlignedBytes::<8, Align4>::new([1, 0, 0, 0, 2, 0, 0, 0]);
+//! let words = briny::raw::slice_from_bytes::<u32>(&buf).unwrap();
+//! assert_eq!(words, [u32::from_le_bytes([1, 0, 0, 0]), u32::from_le_bytes([2, 0, 0, 0])]);
+//! ```
+//!
+//! # Padding
+//!
+//! A wrapped value whose size is not a multiple of the alignment is followed
+//! by padding, so neither wrapper is [`Pod`]. Cast the wrapped bytes instead,
+//! which [`Aligned::as_bytes`] and the `[u8]` view of [`AlignedBytes`] expose.
+//!
+//! In the other direction, [`Unaligned`] stores a [`Pod`] value at byte
+//! alignment so it can be embedded in packed layouts.
+
+use crate::traits::{InteriorImmutable, Pod, StableLayout};
+use core::{fmt, ops};
+
+mod unaligned;
+pub use unaligned::Unaligned;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An alignment that [`Aligned`] and [`AlignedBytes`] can be raised to.
+///
+/// This trait is sealed; the only alignments are [`Align2`]..[`Align4096`].
+pub trait Alignment: sealed::Sealed + Copy + 'static {
+    /// The alignment in bytes.
+    const ALIGN: usize;
+}
+
+macro_rules! alignment {
+    ($($name:ident = $align:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("A zero-sized marker aligned to ", stringify!($align), " bytes.")]
+            #[repr(align($align))]
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $name;
+
+            impl sealed::Sealed for $name {}
+
+            impl Alignment for $name {
+                const ALIGN: usize = $align;
+            }
+
+            unsafe impl StableLayout for $name {}
+            unsafe impl InteriorImmutable for $name {}
+        )*
+    };
+}
+
+alignment! {
+    Align2 = 2,
+    Align4 = 4,
+    Align8 = 8,
+    Align16 = 16,
+    Align32 = 32,
+    Align64 = 64,
+    Align128 = 128,
+    Align256 = 256,
+    Align512 = 512,
+    Align1024 = 1024,
+    Align2048 = 2048,
+    Align4096 = 4096,
+}
+
+/// A `T` aligned to at least `A`.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aligned<T, A: Alignment> {
+    align: [A; 0],
+    value: T,
+}
+
+impl<T, A: Alignment> Aligned<T, A> {
+    /// Wraps `value`.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self { align: [], value }
+    }
+
+    /// Unwraps the value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Borrows the value.
+    #[inline(always)]
+    pub const fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Mutably borrows the value.
+    #[inline(always)]
+    pub const fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Pod, A: Alignment> Aligned<T, A> {
+    /// Views the value as its raw bytes.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        crate::raw::to_bytes(&self.value)
+    }
+
+    /// Views the value as its raw, mutable bytes.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_bytes_mut(&mut self) -> &mut [u8] {
+        crate::raw::to_bytes_mut(&mut self.value)
+    }
+}
+
+impl<T: Default, A: Alignment> Default for Aligned<T, A> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, A: Alignment> From<T> for Aligned<T, A> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T, A: Alignment> ops::Deref for Aligned<T, A> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T, A: Alignment> ops::DerefMut for Aligned<T, A> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: fmt::Debug, A: Alignment> fmt::Debug for Aligned<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Aligned").field(&self.value).finish()
+    }
+}
+
+unsafe impl<T: StableLayout, A: Alignment> StableLayout for Aligned<T, A> {}
+unsafe impl<T: InteriorImmutable, A: Alignment> InteriorImmutable for Aligned<T, A> {}
+
+/// `N` bytes aligned to at least `A`, viewed as a `[u8]`.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AlignedBytes<const N: usize, A: Alignment> {
+    align: [A; 0],
+    bytes: [u8; N],
+}
+
+impl<const N: usize, A: Alignment> AlignedBytes<N, A> {
+    /// A buffer of zeroes.
+    pub const ZERO: Self = Self::new([0; N]);
+
+    /// Wraps `bytes`.
+    #[inline(always)]
+    #[must_use]
+    pub const fn new(bytes: [u8; N]) -> Self {
+        Self { align: [], bytes }
+    }
+
+    /// Unwraps the bytes.
+    #[inline(always)]
+    #[must_use]
+    pub const fn into_inner(self) -> [u8; N] {
+        self.bytes
+    }
+
+    /// Borrows the bytes as an array.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Mutably borrows the bytes as an array.
+    #[inline(always)]
+    #[must_use]
+    pub const fn as_array_mut(&mut self) -> &mut [u8; N] {
+        &mut self.bytes
+    }
+}
+
+impl<const N: usize, A: Alignment> Default for AlignedBytes<N, A> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize, A: Alignment> From<[u8; N]> for AlignedBytes<N, A> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl<const N: usize, A: Alignment> ops::Deref for AlignedBytes<N, A> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<const N: usize, A: Alignment> ops::DerefMut for AlignedBytes<N, A> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl<const N: usize, A: Alignment> AsRef<[u8]> for AlignedBytes<N, A> {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl<const N: usize, A: Alignment> AsMut<[u8]> for AlignedBytes<N, A> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+}
+
+impl<const N: usize, A: Alignment> fmt::Debug for AlignedBytes<N, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AlignedBytes").field(&self.bytes).finish()
+    }
+}
+
+unsafe impl<const N: usize, A: Alignment> StableLayout for AlignedBytes<N, A> {}
+unsafe impl<const N: usize, A: Alignment> InteriorImmutable for AlignedBytes<N, A> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::{from_bytes, ref_from_bytes};
+
+    #[test]
+    fn markers_have_their_alignment() {
+        assert_eq!(align_of::<Align2>(), Align2::ALIGN);
+        assert_eq!(align_of::<Align4096>(), Align4096::ALIGN);
+        assert_eq!(size_of::<Align64>(), 0);
+        assert_eq!(align_of::<AlignedBytes<64, Align64>>(), 64);
+        assert_eq!(size_of::<Aligned<[u16; 4], Align8>>(), 8);
+    }
+
+    #[test]
+    fn aligned_bytes_are_castable() {
+        static TABLE: AlignedBytes<16, Align16> = AlignedBytes::new([7; 16]);
+
+        assert_eq!(TABLE.as_ptr() as usize % 16, 0);
+        let value: u128 = from_bytes(&TABLE).unwrap();
+        assert_eq!(value, u128::from_ne_bytes([7; 16]));
+
+        let mut buf = AlignedBytes::<8, Align8>::ZERO;
+        buf.copy_from_slice(&5u64.to_ne_bytes());
+        assert_eq!(*ref_from_bytes::<u64>(&buf).unwrap(), 5);
+    }
+
+    #[test]
+    fn aligned_value_roundtrip() {
+        let mut value = Aligned::<[u8; 4], Align4>::new([1, 2, 3, 4]);
+        value[0] = 9;
+        assert_eq!(value.as_bytes(), [9, 2, 3, 4]);
+        assert_eq!(
+            *ref_from_bytes::<u32>(value.as_bytes()).unwrap(),
+            u32::from_ne_bytes([9, 2, 3, 4])
+        );
+        assert_eq!(value.into_inner(), [9, 2, 3, 4]);
+    }
+
+    #[test]
+    fn padded_wrappers_hide_their_padding() {
+        let mut value = Aligned::<u8, Align4>::new(1);
+        value.as_bytes_mut()[0] = 2;
+        assert_eq!(size_of_val(&value), 4);
+        assert_eq!(value.as_bytes(), [2]);
+
+        let buf = AlignedBytes::<3, Align4>::new([1, 2, 3]);
+        assert_eq!(size_of_val(&buf), 4);
+        assert_eq!(*buf, [1, 2, 3]);
+    }
+}
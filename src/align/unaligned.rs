@@ -0,0 +1,167 @@
+use crate::{
+    raw,
+    traits::{CheckedBitPattern, InteriorImmutable, Pod, RawConvert, StableLayout},
+};
+use core::{fmt, hash};
+
+/// A [`Pod`] value stored at byte alignment.
+///
+/// `Unaligned<T>` has the size of `T` and an alignment of one, so it can sit at
+/// any offset of a packed layout. The value is only ever read and written by
+/// copy, never borrowed, so no unaligned reference is created:
+///
+/// ```
+/// use briny::align::Unaligned;
+///
+/// #[repr(C)]
+/// struct Record {
+///     tag: u8,
+///     value: Unaligned<u64>,
+/// }
+///
+/// let mut record = Record { tag: 1, value: Unaligned::new(7) };
+/// record.value.update(|value| *value += 1);
+/// assert_eq!(size_of::<Record>(), 9);
+/// assert_eq!(record.value.get(), 8);
+/// ```
+#[repr(C, packed)]
+pub struct Unaligned<T>(T);
+
+impl<T: Pod> Unaligned<T> {
+    /// Stores `value`.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Copies the value out.
+    #[inline(always)]
+    pub const fn get(&self) -> T {
+        raw::read_unaligned::<Self, T>(self)
+    }
+
+    /// Overwrites the value.
+    #[inline(always)]
+    pub const fn set(&mut self, value: T) {
+        raw::write_unaligned(self, value);
+    }
+
+    /// Modifies the value through an aligned copy, writing it back afterwards.
+    #[inline(always)]
+    pub fn update<R>(&mut self, f: impl FnOnce(&mut T) -> R) -> R {
+        let mut value = self.get();
+        let result = f(&mut value);
+        self.set(value);
+        result
+    }
+
+    /// Moves the value out.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.get()
+    }
+}
+
+impl<T: Pod> Clone for Unaligned<T> {
+    fn clone(&self) -> Self {
+        Self::new(self.get())
+    }
+}
+
+impl<T: Pod + Copy> Copy for Unaligned<T> {}
+
+impl<T: Pod + Default> Default for Unaligned<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Pod> From<T> for Unaligned<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Pod + PartialEq> PartialEq for Unaligned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Pod + Eq> Eq for Unaligned<T> {}
+
+impl<T: Pod + hash::Hash> hash::Hash for Unaligned<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
+
+impl<T: Pod + fmt::Debug> fmt::Debug for Unaligned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Unaligned").field(&self.get()).finish()
+    }
+}
+
+unsafe impl<T: Pod> StableLayout for Unaligned<T> {}
+unsafe impl<T: Pod> RawConvert for Unaligned<T> {}
+unsafe impl<T: Pod + InteriorImmutable> InteriorImmutable for Unaligned<T> {}
+unsafe impl<T: Pod> crate::traits::Unaligned for Unaligned<T> {}
+unsafe impl<T: Pod> Pod for Unaligned<T> {}
+
+unsafe impl<T: Pod + CheckedBitPattern> CheckedBitPattern for Unaligned<T> {
+    #[inline(always)]
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        T::is_valid_bit_pattern(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::{from_bytes_unaligned, ref_from_bytes, to_bytes};
+
+    #[repr(C)]
+    struct Packet {
+        kind: u8,
+        len: Unaligned<u32>,
+        payload: Unaligned<u64>,
+    }
+
+    unsafe impl StableLayout for Packet {}
+    unsafe impl RawConvert for Packet {}
+    unsafe impl Pod for Packet {}
+    unsafe impl InteriorImmutable for Packet {}
+
+    #[test]
+    fn layout_is_packed() {
+        assert_eq!(align_of::<Unaligned<u128>>(), 1);
+        assert_eq!(size_of::<Unaligned<u128>>(), 16);
+        assert_eq!(size_of::<Packet>(), 13);
+    }
+
+    #[test]
+    fn get_set_update() {
+        let mut value = Unaligned::new(10u32);
+        value.set(20);
+        assert_eq!(value.update(|v| core::mem::replace(v, 30)), 20);
+        assert_eq!(value.get(), 30);
+        assert_eq!(value, Unaligned::from(30));
+    }
+
+    #[test]
+    fn casts_at_any_offset() {
+        let mut bytes = [0u8; 14];
+        bytes[1] = 3;
+        bytes[2..6].copy_from_slice(&8u32.to_ne_bytes());
+        bytes[6..].copy_from_slice(&u64::MAX.to_ne_bytes());
+
+        let packet = ref_from_bytes::<Packet>(&bytes[1..]).unwrap();
+        assert_eq!(packet.kind, 3);
+        assert_eq!(packet.len.get(), 8);
+        assert_eq!(packet.payload.get(), u64::MAX);
+        assert_eq!(to_bytes(packet), &bytes[1..]);
+
+        let len = from_bytes_unaligned::<Unaligned<u32>>(&bytes[2..6]).unwrap();
+        assert_eq!(len.into_inner(), 8);
+    }
+}
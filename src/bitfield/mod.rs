@@ -0,0 +1,446 @@
+//! Typed bit fields packed into [`Pod`] integers.
+//!
+//! [`bitfield!`](macro@crate::bitfield) declares a `repr(transparent)` newtype over a
+//! [`BitStorage`] integer with a getter and setter per bit range. Fields are
+//! `bool`, unsigned integers, or enums declared with
+//! [`bitfield_enum!`](crate::bitfield_enum), whose getter rejects values that
+//! are not one of the declared variants. Overlapping fields, fields past the
+//! end of the storage and fields too narrow or too wide for their type fail to
+//! compile.
+//!
+//! ```
+//! briny::bitfield_enum! {
+//!     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//!     pub enum Mode {
+//!         Idle = 0,
+//!         Run = 1,
+//!         Sleep = 3,
+//!     }
+//! }
+//!
+//! briny::bitfield! {
+//!     /// A control register.
+//!     pub struct Control(u32) {
+//!         /// Turns the peripheral on.
+//!         pub enable, set_enable: bool [0],
+//!         pub mode, set_mode: Mode [1..=2],
+//!         pub divider, set_divider: u8 [8..=15],
+//!     }
+//! }
+//!
+//! let mut control = Control::from_bits(0);
+//! control.set_enable(true);
+//! control.set_mode(Mode::Sleep);
+//! control.set_divider(0x40);
+//! assert_eq!(control.into_bits(), 0x4007);
+//! assert_eq!(control.mode(), Ok(Mode::Sleep));
+//!
+//! assert!(Control::from_bits(0b100).mode().is_err());
+//! ```
+//!
+//! [`Pod`]: crate::traits::Pod
+
+use crate::{
+    endian::{ByteOrder, U16, U32, U64},
+    traits::Pod,
+};
+
+/// An integer that bit fields can be packed into.
+pub trait BitStorage: Pod + Copy {
+    /// The number of bits available.
+    const BITS: u32;
+
+    /// Widens the value to a `u64`.
+    fn to_u64(self) -> u64;
+
+    /// Narrows a `u64` back, dropping any bit beyond [`Self::BITS`].
+    fn from_u64(bits: u64) -> Self;
+}
+
+macro_rules! native_storage {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl BitStorage for $t {
+                const BITS: u32 = <$t>::BITS;
+
+                #[inline(always)]
+                fn to_u64(self) -> u64 {
+                    u64::from(self)
+                }
+
+                #[inline(always)]
+                #[allow(clippy::cast_possible_truncation)]
+                fn from_u64(bits: u64) -> Self {
+                    bits as Self
+                }
+            }
+        )*
+    };
+}
+
+native_storage!(u8, u16, u32, u64);
+
+macro_rules! endian_storage {
+    ($($name:ident($native:ty)),* $(,)?) => {
+        $(
+            impl<O: ByteOrder> BitStorage for $name<O> {
+                const BITS: u32 = <$native>::BITS;
+
+                #[inline(always)]
+                fn to_u64(self) -> u64 {
+                    u64::from(self.get())
+                }
+
+                #[inline(always)]
+                #[allow(clippy::cast_possible_truncation)]
+                fn from_u64(bits: u64) -> Self {
+                    Self::new(bits as $native)
+                }
+            }
+        )*
+    };
+}
+
+endian_storage!(U16(u16), U32(u32), U64(u64));
+
+/// A type that can be stored in a bit field.
+pub trait BitFieldValue: Sized {
+    /// What the getter returns: `Self`, or a `Result` for types that reject
+    /// some bit patterns.
+    type Output;
+
+    /// The narrowest field that can hold every value.
+    const MIN_BITS: u32;
+
+    /// The widest field whose every value can be represented.
+    const MAX_BITS: u32;
+
+    /// Converts the bits of a field, already shifted down and masked.
+    fn from_raw(raw: u64) -> Self::Output;
+
+    /// Converts the value into the bits of a field.
+    fn into_raw(self) -> u64;
+}
+
+impl BitFieldValue for bool {
+    type Output = Self;
+
+    const MIN_BITS: u32 = 1;
+
+    const MAX_BITS: u32 = 1;
+
+    #[inline(always)]
+    fn from_raw(raw: u64) -> Self {
+        raw != 0
+    }
+
+    #[inline(always)]
+    fn into_raw(self) -> u64 {
+        u64::from(self)
+    }
+}
+
+macro_rules! int_value {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl BitFieldValue for $t {
+                type Output = Self;
+
+                const MIN_BITS: u32 = 1;
+
+                const MAX_BITS: u32 = <$t>::BITS;
+
+                #[inline(always)]
+                #[allow(clippy::cast_possible_truncation)]
+                fn from_raw(raw: u64) -> Self {
+                    raw as Self
+                }
+
+                #[inline(always)]
+                fn into_raw(self) -> u64 {
+                    u64::from(self)
+                }
+            }
+        )*
+    };
+}
+
+int_value!(u8, u16, u32, u64);
+
+/// The mask of bits `lo..=hi`.
+#[doc(hidden)]
+#[inline(always)]
+#[must_use]
+pub const fn mask(lo: u32, hi: u32) -> u64 {
+    let width = hi - lo + 1;
+    if width >= u64::BITS {
+        u64::MAX
+    } else {
+        ((1 << width) - 1) << lo
+    }
+}
+
+/// The number of bits needed to store the largest of `values`.
+#[doc(hidden)]
+#[must_use]
+pub const fn bits_needed(values: &[u64]) -> u32 {
+    let mut max = 0;
+    let mut i = 0;
+    while i < values.len() {
+        if values[i] > max {
+            max = values[i];
+        }
+        i += 1;
+    }
+    u64::BITS - max.leading_zeros()
+}
+
+/// Checks every field of a bit field declaration at compile time.
+///
+/// Each field is `(lo, hi, min_bits, max_bits)`.
+#[doc(hidden)]
+pub const fn check_fields(storage_bits: u32, fields: &[(u32, u32, u32, u32)]) {
+    let mut used = 0u64;
+    let mut i = 0;
+    while i < fields.len() {
+        let (lo, hi, min_bits, max_bits) = fields[i];
+        assert!(lo <= hi, "bit field range is reversed");
+        assert!(hi < storage_bits, "bit field extends past the storage");
+        let width = hi - lo + 1;
+        assert!(width >= min_bits, "bit field is too narrow for its type");
+        assert!(width <= max_bits, "bit field is too wide for its type");
+        let mask = mask(lo, hi);
+        assert!(used & mask == 0, "bit fields overlap");
+        used |= mask;
+        i += 1;
+    }
+}
+
+/// Declares a `repr(transparent)` [`Pod`](crate::traits::Pod) newtype over a
+/// [`BitStorage`] integer with typed accessors for bit ranges.
+///
+/// Each field is written as `visibility getter, setter: Type [lo..=hi]`, or
+/// `[bit]` for a single bit. Setters truncate values to the width of their
+/// field.
+///
+/// ```compile_fail
+/// briny::bitfield! {
+///     struct Flags(u8) {
+///         low, set_low: u8 [0..=3],
+///         mid, set_mid: u8 [3..=5],
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! bitfield {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident($storage:ty) {
+            $(
+                $(#[$field_meta:meta])*
+                $field_vis:vis $get:ident, $set:ident : $ty:ty [$lo:literal $(..= $hi:literal)?]
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $name($storage);
+
+        const _: () = $crate::bitfield::check_fields(
+            <$storage as $crate::bitfield::BitStorage>::BITS,
+            &[$((
+                $lo,
+                $crate::__bitfield_hi!($lo $(, $hi)?),
+                <$ty as $crate::bitfield::BitFieldValue>::MIN_BITS,
+                <$ty as $crate::bitfield::BitFieldValue>::MAX_BITS,
+            )),*],
+        );
+
+        impl $name {
+            /// Wraps raw bits.
+            #[inline(always)]
+            #[must_use]
+            pub const fn from_bits(bits: $storage) -> Self {
+                Self(bits)
+            }
+
+            /// Unwraps the raw bits.
+            #[inline(always)]
+            #[must_use]
+            pub const fn into_bits(self) -> $storage {
+                self.0
+            }
+
+            $(
+                $(#[$field_meta])*
+                #[inline(always)]
+                #[must_use]
+                $field_vis fn $get(&self) -> <$ty as $crate::bitfield::BitFieldValue>::Output {
+                    let lo = $lo;
+                    let mask = $crate::bitfield::mask(lo, $crate::__bitfield_hi!($lo $(, $hi)?));
+                    let bits = $crate::bitfield::BitStorage::to_u64(self.0);
+                    <$ty as $crate::bitfield::BitFieldValue>::from_raw((bits & mask) >> lo)
+                }
+
+                #[doc = concat!("Sets the field read by [`Self::", stringify!($get), "`].")]
+                #[inline(always)]
+                $field_vis fn $set(&mut self, value: $ty) {
+                    let lo = $lo;
+                    let mask = $crate::bitfield::mask(lo, $crate::__bitfield_hi!($lo $(, $hi)?));
+                    let bits = $crate::bitfield::BitStorage::to_u64(self.0);
+                    let raw = $crate::bitfield::BitFieldValue::into_raw(value);
+                    self.0 = $crate::bitfield::BitStorage::from_u64((bits & !mask) | ((raw << lo) & mask));
+                }
+            )*
+        }
+
+        unsafe impl $crate::traits::StableLayout for $name {}
+        unsafe impl $crate::traits::RawConvert for $name {}
+        unsafe impl $crate::traits::InteriorImmutable for $name {}
+        unsafe impl $crate::traits::Pod for $name {}
+
+        unsafe impl $crate::traits::CheckedBitPattern for $name {
+            #[inline(always)]
+            fn is_valid_bit_pattern(_bytes: &[u8]) -> bool {
+                true
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bitfield_hi {
+    ($lo:literal) => {
+        $lo
+    };
+    ($lo:literal, $hi:literal) => {
+        $hi
+    };
+}
+
+/// Declares a fieldless enum usable as a [`bitfield!`](macro@crate::bitfield) field.
+///
+/// The getter of such a field returns
+/// [`BrinyError::INVALID_BITPATTERN`](crate::BrinyError::INVALID_BITPATTERN)
+/// for bits that match none of the declared variants.
+#[macro_export]
+macro_rules! bitfield_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant = $value),*
+        }
+
+        impl $crate::bitfield::BitFieldValue for $name {
+            type Output = ::core::result::Result<Self, $crate::BrinyError>;
+
+            const MIN_BITS: u32 = $crate::bitfield::bits_needed(&[$($value),*]);
+
+            const MAX_BITS: u32 = u64::BITS;
+
+            #[inline(always)]
+            fn from_raw(raw: u64) -> Self::Output {
+                $(
+                    if raw == $value {
+                        return Ok(Self::$variant);
+                    }
+                )*
+                Err($crate::BrinyError::INVALID_BITPATTERN)
+            }
+
+            #[inline(always)]
+            fn into_raw(self) -> u64 {
+                match self {
+                    $(Self::$variant => $value),*
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::endian::{BigEndian, U16};
+    use crate::raw::{cast, to_bytes};
+    use crate::BrinyError;
+
+    crate::bitfield_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Kind {
+            Data = 0,
+            Ack = 2,
+        }
+    }
+
+    crate::bitfield! {
+        struct Status(u8) {
+            ready, set_ready: bool [0],
+            kind, set_kind: Kind [1..=2],
+            count, set_count: u8 [3..=7],
+        }
+    }
+
+    crate::bitfield! {
+        struct Header(U16<BigEndian>) {
+            version, set_version: u8 [12..=15],
+            length, set_length: u16 [0..=11],
+        }
+    }
+
+    crate::bitfield! {
+        struct Wide(u64) {
+            all, set_all: u64 [0..=63],
+        }
+    }
+
+    #[test]
+    fn fields_roundtrip() {
+        let mut status = Status::default();
+        status.set_ready(true);
+        status.set_kind(Kind::Ack);
+        status.set_count(31);
+        assert_eq!(status.into_bits(), 0b1111_1101);
+        assert!(status.ready());
+        assert_eq!(status.kind(), Ok(Kind::Ack));
+        assert_eq!(status.count(), 31);
+
+        status.set_count(0xFF);
+        assert_eq!(status.into_bits(), 0b1111_1101);
+        status.set_ready(false);
+        assert_eq!(status.into_bits(), 0b1111_1100);
+    }
+
+    #[test]
+    fn undeclared_variants_are_rejected() {
+        let status = Status::from_bits(0b010);
+        assert_eq!(status.kind(), Err(BrinyError::INVALID_BITPATTERN));
+    }
+
+    #[test]
+    fn endian_storage() {
+        let mut header = Header::default();
+        header.set_version(4);
+        header.set_length(0x123);
+        assert_eq!(to_bytes(&header), [0x41, 0x23]);
+        assert_eq!(header.into_bits().get(), 0x4123);
+        assert_eq!(Header::from_bits(U16::new(0x4123)), header);
+        assert_eq!(header.version(), 4);
+        assert_eq!(header.length(), 0x123);
+    }
+
+    #[test]
+    fn full_width_field() {
+        let mut wide = Wide::from_bits(0);
+        wide.set_all(u64::MAX);
+        assert_eq!(wide.all(), u64::MAX);
+        assert_eq!(cast::<Wide, u64>(&wide), u64::MAX);
+        assert_eq!(wide.into_bits(), u64::MAX);
+    }
+}
@@ -0,0 +1,204 @@
+//! Constant-time comparison and selection of [`Pod`] values.
+//!
+//! Comparing secrets such as MACs or tokens with `==` returns as soon as a
+//! byte differs, which leaks how much of the secret matched. The functions in
+//! this module work on the raw bytes of their inputs and always touch every
+//! byte without data-dependent branches. Their results are a [`Choice`], which
+//! only becomes a `bool` through an explicit [`Choice::to_bool`] call.
+//!
+//! ```
+//! use briny::ct::ct_eq;
+//!
+//! let expected = [0x5Au8; 32];
+//! let received = [0x5Au8; 32];
+//! assert!(ct_eq(&expected, &received).to_bool());
+//! ```
+
+use crate::{
+    raw,
+    traits::{Pod, Zeroable},
+};
+use core::{hint::black_box, ops};
+
+/// A constant-time boolean, holding either `0` or `1`.
+#[derive(Debug, Clone, Copy)]
+pub struct Choice(u8);
+
+impl Choice {
+    /// The false choice.
+    pub const FALSE: Self = Self(0);
+
+    /// The true choice.
+    pub const TRUE: Self = Self(1);
+
+    /// Creates a choice from `0` or `1`.
+    ///
+    /// Any other value is reduced to its lowest bit.
+    #[inline]
+    #[must_use]
+    pub fn from_u8(value: u8) -> Self {
+        Self(black_box(value & 1))
+    }
+
+    /// Returns the choice as `0` or `1`.
+    #[inline]
+    #[must_use]
+    pub const fn unwrap_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Converts the choice into a `bool`.
+    ///
+    /// Branching on the result is no longer constant-time, so call this only
+    /// once the outcome may be revealed.
+    #[inline]
+    #[must_use]
+    pub fn to_bool(self) -> bool {
+        black_box(self.0) == 1
+    }
+
+    /// A byte with every bit set if the choice is true, and none otherwise.
+    #[inline]
+    fn mask(self) -> u8 {
+        black_box(self.0).wrapping_neg()
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(value: bool) -> Self {
+        Self::from_u8(u8::from(value))
+    }
+}
+
+impl ops::BitAnd for Choice {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl ops::BitOr for Choice {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl ops::BitXor for Choice {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl ops::Not for Choice {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(self.0 ^ 1)
+    }
+}
+
+/// Folds the bytes with `|` into one byte that is zero only if all of them are.
+#[inline]
+fn fold(bytes: impl Iterator<Item = u8>) -> u8 {
+    bytes.fold(0, |acc, byte| black_box(acc | byte))
+}
+
+/// Whether a byte is zero, without branching.
+#[inline]
+fn byte_is_zero(byte: u8) -> Choice {
+    Choice::from_u8(((byte | byte.wrapping_neg()) >> 7) ^ 1)
+}
+
+/// Checks whether two [`Pod`] values have identical bytes in constant time.
+#[inline]
+#[must_use]
+pub fn ct_eq<T: Pod>(a: &T, b: &T) -> Choice {
+    let (a, b) = (raw::to_bytes(a), raw::to_bytes(b));
+    byte_is_zero(fold(a.iter().zip(b).map(|(x, y)| x ^ y)))
+}
+
+/// Checks whether two slices of [`Pod`] values have identical bytes.
+///
+/// The time taken depends on the lengths, which are not treated as secret:
+/// slices of different lengths are unequal immediately.
+#[inline]
+#[must_use]
+pub fn ct_eq_slices<T: Pod>(a: &[T], b: &[T]) -> Choice {
+    if a.len() != b.len() {
+        return Choice::FALSE;
+    }
+    let (a, b) = (raw::slice_to_bytes(a), raw::slice_to_bytes(b));
+    byte_is_zero(fold(a.iter().zip(b).map(|(x, y)| x ^ y)))
+}
+
+/// Checks whether every byte of a [`Pod`] value is zero in constant time.
+#[inline]
+#[must_use]
+pub fn ct_is_zero<T: Pod>(value: &T) -> Choice {
+    byte_is_zero(fold(raw::to_bytes(value).iter().copied()))
+}
+
+/// Returns a copy of `a` if `choice` is false, and of `b` if it is true.
+#[inline]
+#[must_use]
+pub fn ct_select<T: Pod>(a: &T, b: &T, choice: Choice) -> T {
+    let mask = choice.mask();
+    let mut out = T::zeroed();
+    let bytes = raw::to_bytes(a).iter().zip(raw::to_bytes(b));
+    for (out, (x, y)) in raw::to_bytes_mut(&mut out).iter_mut().zip(bytes) {
+        *out = x ^ (mask & (x ^ y));
+    }
+    out
+}
+
+/// Swaps `a` and `b` if `choice` is true, leaving them untouched otherwise.
+#[inline]
+pub fn ct_swap<T: Pod>(a: &mut T, b: &mut T, choice: Choice) {
+    let mask = choice.mask();
+    let bytes = raw::to_bytes_mut(b).iter_mut();
+    for (x, y) in raw::to_bytes_mut(a).iter_mut().zip(bytes) {
+        let diff = mask & (*x ^ *y);
+        *x ^= diff;
+        *y ^= diff;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn choice_logic() {
+        assert!((Choice::TRUE & !Choice::FALSE).to_bool());
+        assert!(!(Choice::TRUE ^ Choice::TRUE).to_bool());
+        assert!((Choice::FALSE | Choice::from(true)).to_bool());
+        assert_eq!(Choice::from_u8(3).unwrap_u8(), 1);
+    }
+
+    #[test]
+    fn equality() {
+        assert!(ct_eq(&[1u32, 2], &[1, 2]).to_bool());
+        assert!(!ct_eq(&[1u32, 2], &[1, 3]).to_bool());
+        assert!(ct_eq_slices(&[1u16, 2, 3], &[1, 2, 3]).to_bool());
+        assert!(!ct_eq_slices(&[1u16, 2, 3], &[1, 2]).to_bool());
+        assert!(ct_is_zero(&[0u64; 4]).to_bool());
+        assert!(!ct_is_zero(&0x100u32).to_bool());
+    }
+
+    #[test]
+    fn selection_and_swapping() {
+        assert_eq!(ct_select(&1u64, &2, Choice::FALSE), 1);
+        assert_eq!(ct_select(&1u64, &2, Choice::TRUE), 2);
+
+        let (mut a, mut b) = ([1u8; 3], [2u8; 3]);
+        ct_swap(&mut a, &mut b, Choice::FALSE);
+        assert_eq!((a, b), ([1; 3], [2; 3]));
+        ct_swap(&mut a, &mut b, Choice::TRUE);
+        assert_eq!((a, b), ([2; 3], [1; 3]));
+    }
+}
@@ -0,0 +1,397 @@
+//! Integers and floats with an explicit byte order.
+//!
+//! Every type in this module is stored as a plain byte array, so it is [`Pod`],
+//! [`Unaligned`] and identical on every host. The byte order is chosen with a
+//! type parameter: [`LittleEndian`], [`BigEndian`] or [`NetworkEndian`].
+//!
+//! [`Pod`]: crate::traits::Pod
+//! [`Unaligned`]: crate::traits::Unaligned
+
+use crate::traits::{
+    CheckedBitPattern, InteriorImmutable, Pod, RawConvert, StableLayout, Unaligned,
+};
+use core::{cmp::Ordering, fmt, hash, marker::PhantomData, ops};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A byte order used by the types in this module.
+///
+/// This trait is sealed; the only orders are [`LittleEndian`] and [`BigEndian`].
+pub trait ByteOrder: sealed::Sealed + 'static {
+    /// Whether values are stored least significant byte first.
+    const IS_LITTLE: bool;
+}
+
+/// Least significant byte first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LittleEndian {}
+
+/// Most significant byte first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BigEndian {}
+
+/// The byte order used by network protocols, which is [`BigEndian`].
+pub type NetworkEndian = BigEndian;
+
+/// The byte order of the host.
+#[cfg(target_endian = "little")]
+pub type NativeEndian = LittleEndian;
+
+/// The byte order of the host.
+#[cfg(target_endian = "big")]
+pub type NativeEndian = BigEndian;
+
+impl sealed::Sealed for LittleEndian {}
+impl sealed::Sealed for BigEndian {}
+
+impl ByteOrder for LittleEndian {
+    const IS_LITTLE: bool = true;
+}
+
+impl ByteOrder for BigEndian {
+    const IS_LITTLE: bool = false;
+}
+
+macro_rules! endian_type {
+    (
+        $(#[$meta:meta])* $name:ident, $native:ty, $bits:ty,
+        |$value:ident| $to_bits:expr, |$raw:ident| $from_bits:expr
+    ) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        pub struct $name<O: ByteOrder>([u8; size_of::<$native>()], PhantomData<O>);
+
+        impl<O: ByteOrder> $name<O> {
+            /// The zero value.
+            pub const ZERO: Self = Self([0; size_of::<$native>()], PhantomData);
+
+            /// Stores `value` in byte order `O`.
+            #[inline(always)]
+            #[must_use]
+            pub const fn new($value: $native) -> Self {
+                let bits: $bits = $to_bits;
+                let bytes = if O::IS_LITTLE {
+                    bits.to_le_bytes()
+                } else {
+                    bits.to_be_bytes()
+                };
+                Self(bytes, PhantomData)
+            }
+
+            /// Loads the value in native byte order.
+            #[inline(always)]
+            #[must_use]
+            pub const fn get(self) -> $native {
+                let $raw = if O::IS_LITTLE {
+                    <$bits>::from_le_bytes(self.0)
+                } else {
+                    <$bits>::from_be_bytes(self.0)
+                };
+                $from_bits
+            }
+
+            /// Stores `value` in byte order `O`.
+            #[inline(always)]
+            pub const fn set(&mut self, value: $native) {
+                *self = Self::new(value);
+            }
+
+            /// Replaces the value with the result of `f`.
+            #[inline(always)]
+            pub fn update(&mut self, f: impl FnOnce($native) -> $native) {
+                self.set(f(self.get()));
+            }
+
+            /// Wraps the raw bytes, which must already be in byte order `O`.
+            #[inline(always)]
+            #[must_use]
+            pub const fn from_bytes(bytes: [u8; size_of::<$native>()]) -> Self {
+                Self(bytes, PhantomData)
+            }
+
+            /// Returns the raw bytes in byte order `O`.
+            #[inline(always)]
+            #[must_use]
+            pub const fn to_bytes(self) -> [u8; size_of::<$native>()] {
+                self.0
+            }
+        }
+
+        impl<O: ByteOrder> Clone for $name<O> {
+            #[inline(always)]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<O: ByteOrder> Copy for $name<O> {}
+
+        impl<O: ByteOrder> Default for $name<O> {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::ZERO
+            }
+        }
+
+        impl<O: ByteOrder> From<$native> for $name<O> {
+            #[inline(always)]
+            fn from(value: $native) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<O: ByteOrder> From<$name<O>> for $native {
+            #[inline(always)]
+            fn from(value: $name<O>) -> Self {
+                value.get()
+            }
+        }
+
+        impl<O: ByteOrder> PartialEq for $name<O> {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                self.get() == other.get()
+            }
+        }
+
+        impl<O: ByteOrder> PartialEq<$native> for $name<O> {
+            #[inline(always)]
+            fn eq(&self, other: &$native) -> bool {
+                self.get() == *other
+            }
+        }
+
+        impl<O: ByteOrder> fmt::Debug for $name<O> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.get(), f)
+            }
+        }
+
+        impl<O: ByteOrder> fmt::Display for $name<O> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.get(), f)
+            }
+        }
+
+        endian_ops!($name, $native, Add add, AddAssign add_assign);
+        endian_ops!($name, $native, Sub sub, SubAssign sub_assign);
+        endian_ops!($name, $native, Mul mul, MulAssign mul_assign);
+        endian_ops!($name, $native, Div div, DivAssign div_assign);
+        endian_ops!($name, $native, Rem rem, RemAssign rem_assign);
+
+        unsafe impl<O: ByteOrder> StableLayout for $name<O> {}
+        unsafe impl<O: ByteOrder> RawConvert for $name<O> {}
+        unsafe impl<O: ByteOrder> InteriorImmutable for $name<O> {}
+        unsafe impl<O: ByteOrder> Unaligned for $name<O> {}
+        unsafe impl<O: ByteOrder> Pod for $name<O> {}
+
+        unsafe impl<O: ByteOrder> CheckedBitPattern for $name<O> {
+            #[inline(always)]
+            fn is_valid_bit_pattern(_bytes: &[u8]) -> bool {
+                true
+            }
+        }
+    };
+}
+
+macro_rules! endian_ops {
+    ($name:ident, $native:ty, $op:ident $method:ident, $op_assign:ident $method_assign:ident) => {
+        impl<O: ByteOrder> ops::$op for $name<O> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self {
+                Self::new(ops::$op::$method(self.get(), rhs.get()))
+            }
+        }
+
+        impl<O: ByteOrder> ops::$op<$native> for $name<O> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: $native) -> Self {
+                Self::new(ops::$op::$method(self.get(), rhs))
+            }
+        }
+
+        impl<O: ByteOrder> ops::$op_assign for $name<O> {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = ops::$op::$method(*self, rhs);
+            }
+        }
+
+        impl<O: ByteOrder> ops::$op_assign<$native> for $name<O> {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: $native) {
+                *self = ops::$op::$method(*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! endian_int {
+    ($(#[$meta:meta])* $name:ident, $native:ty) => {
+        endian_type!($(#[$meta])* $name, $native, $native, |value| value, |bits| bits);
+
+        impl<O: ByteOrder> $name<O> {
+            /// Adds `rhs`, returning `None` on overflow.
+            #[inline(always)]
+            #[must_use]
+            pub const fn checked_add(self, rhs: $native) -> Option<Self> {
+                match self.get().checked_add(rhs) {
+                    Some(value) => Some(Self::new(value)),
+                    None => None,
+                }
+            }
+
+            /// Subtracts `rhs`, returning `None` on overflow.
+            #[inline(always)]
+            #[must_use]
+            pub const fn checked_sub(self, rhs: $native) -> Option<Self> {
+                match self.get().checked_sub(rhs) {
+                    Some(value) => Some(Self::new(value)),
+                    None => None,
+                }
+            }
+
+            /// Adds `rhs`, wrapping around on overflow.
+            #[inline(always)]
+            #[must_use]
+            pub const fn wrapping_add(self, rhs: $native) -> Self {
+                Self::new(self.get().wrapping_add(rhs))
+            }
+
+            /// Subtracts `rhs`, wrapping around on overflow.
+            #[inline(always)]
+            #[must_use]
+            pub const fn wrapping_sub(self, rhs: $native) -> Self {
+                Self::new(self.get().wrapping_sub(rhs))
+            }
+        }
+
+        impl<O: ByteOrder> Eq for $name<O> {}
+
+        impl<O: ByteOrder> PartialOrd for $name<O> {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<O: ByteOrder> Ord for $name<O> {
+            #[inline(always)]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.get().cmp(&other.get())
+            }
+        }
+
+        impl<O: ByteOrder> hash::Hash for $name<O> {
+            fn hash<H: hash::Hasher>(&self, state: &mut H) {
+                self.get().hash(state);
+            }
+        }
+
+        endian_ops!($name, $native, BitAnd bitand, BitAndAssign bitand_assign);
+        endian_ops!($name, $native, BitOr bitor, BitOrAssign bitor_assign);
+        endian_ops!($name, $native, BitXor bitxor, BitXorAssign bitxor_assign);
+    };
+}
+
+macro_rules! endian_float {
+    ($(#[$meta:meta])* $name:ident, $native:ty, $bits:ty) => {
+        endian_type!(
+            $(#[$meta])* $name, $native, $bits,
+            |value| value.to_bits(), |bits| <$native>::from_bits(bits)
+        );
+
+        impl<O: ByteOrder> PartialOrd for $name<O> {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.get().partial_cmp(&other.get())
+            }
+        }
+    };
+}
+
+endian_int!(
+    /// A `u16` stored in byte order `O`.
+    U16, u16
+);
+endian_int!(
+    /// A `u32` stored in byte order `O`.
+    U32, u32
+);
+endian_int!(
+    /// A `u64` stored in byte order `O`.
+    U64, u64
+);
+endian_int!(
+    /// A `u128` stored in byte order `O`.
+    U128, u128
+);
+endian_int!(
+    /// An `i16` stored in byte order `O`.
+    I16, i16
+);
+endian_int!(
+    /// An `i32` stored in byte order `O`.
+    I32, i32
+);
+endian_int!(
+    /// An `i64` stored in byte order `O`.
+    I64, i64
+);
+endian_int!(
+    /// An `i128` stored in byte order `O`.
+    I128, i128
+);
+endian_float!(
+    /// An `f32` stored in byte order `O`.
+    F32, f32, u32
+);
+endian_float!(
+    /// An `f64` stored in byte order `O`.
+    F64, f64, u64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::{from_bytes_unaligned, to_bytes};
+
+    #[test]
+    fn byte_order_is_explicit() {
+        let le = U32::<LittleEndian>::new(0x1122_3344);
+        let be = U32::<NetworkEndian>::new(0x1122_3344);
+
+        assert_eq!(le.to_bytes(), [0x44, 0x33, 0x22, 0x11]);
+        assert_eq!(be.to_bytes(), [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(to_bytes(&be), &[0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(le.get(), be.get());
+    }
+
+    #[test]
+    fn unaligned_reads_and_arithmetic() {
+        let bytes = [0u8, 0x00, 0x00, 0x01, 0x00];
+        let mut value = from_bytes_unaligned::<U32<BigEndian>>(&bytes[1..]).unwrap();
+        assert_eq!(value, 256);
+
+        value += 1;
+        value.update(|v| v * 2);
+        assert_eq!(u32::from(value), 514);
+        assert_eq!(value.checked_sub(515), None);
+        assert_eq!((value & 0xFF).get(), 2);
+    }
+
+    #[test]
+    fn floats_roundtrip() {
+        let mut value = F64::<BigEndian>::new(1.5);
+        assert_eq!(value.to_bytes()[0], 0x3F);
+        value.set(-2.25);
+        assert_eq!(value.get().to_bits(), (-2.25f64).to_bits());
+        assert_eq!(I16::<LittleEndian>::new(-2).to_bytes(), [0xFE, 0xFF]);
+    }
+}
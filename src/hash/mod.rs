@@ -0,0 +1,194 @@
+//! Equality, ordering and hashing of [`Pod`] values by their raw bytes.
+//!
+//! A [`Pod`] value is fully described by its bytes, so they make a good
+//! identity even for types that do not derive `PartialEq` or `Hash`.
+//! [`ByteKey`] wraps such a value for use in maps and sets, and [`fnv1a64`]
+//! computes a hash that is identical across builds, targets of the same byte
+//! order and versions of this crate.
+
+use crate::{raw, traits::Pod};
+use core::{cmp::Ordering, hash};
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// Hashes bytes with 64-bit FNV-1a.
+///
+/// The result only depends on `bytes`, so it is stable enough to persist.
+#[inline]
+#[must_use]
+pub const fn fnv1a64(bytes: &[u8]) -> u64 {
+    fnv1a64_update(FNV_OFFSET_BASIS, bytes)
+}
+
+/// Continues an [`fnv1a64`] hash with more bytes.
+#[inline]
+#[must_use]
+pub(crate) const fn fnv1a64_update(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+    hash
+}
+
+/// Hashes the bytes of a [`Pod`] value with [`fnv1a64`].
+#[inline]
+#[must_use]
+pub const fn pod_hash<T: Pod>(value: &T) -> u64 {
+    fnv1a64(raw::to_bytes(value))
+}
+
+/// Checks whether two [`Pod`] values have identical bytes.
+#[inline]
+#[must_use]
+pub fn bytes_eq<T: Pod>(a: &T, b: &T) -> bool {
+    raw::to_bytes(a) == raw::to_bytes(b)
+}
+
+/// Compares the bytes of two [`Pod`] values lexicographically.
+///
+/// This is a consistent total order, but not the numeric order of integers
+/// stored least significant byte first.
+#[inline]
+#[must_use]
+pub fn bytes_cmp<T: Pod>(a: &T, b: &T) -> Ordering {
+    raw::to_bytes(a).cmp(raw::to_bytes(b))
+}
+
+/// A [`core::hash::Hasher`] computing [`fnv1a64`] over everything written to it.
+///
+/// Use it with `BuildHasherDefault` for map keys whose hashes must not vary
+/// between runs.
+#[derive(Debug, Clone, Copy)]
+pub struct Fnv1a64(u64);
+
+impl Default for Fnv1a64 {
+    fn default() -> Self {
+        Self(FNV_OFFSET_BASIS)
+    }
+}
+
+impl hash::Hasher for Fnv1a64 {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = fnv1a64_update(self.0, bytes);
+    }
+}
+
+/// A [`Pod`] value that is compared, ordered and hashed by its raw bytes.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ByteKey<T>(pub T);
+
+impl<T: Pod> ByteKey<T> {
+    /// The [`fnv1a64`] hash of the value's bytes.
+    #[inline]
+    #[must_use]
+    pub const fn stable_hash(&self) -> u64 {
+        pod_hash(&self.0)
+    }
+
+    /// The value's raw bytes.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        raw::to_bytes(&self.0)
+    }
+}
+
+impl<T: Pod> PartialEq for ByteKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        bytes_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: Pod> Eq for ByteKey<T> {}
+
+impl<T: Pod> PartialOrd for ByteKey<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Pod> Ord for ByteKey<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        bytes_cmp(&self.0, &other.0)
+    }
+}
+
+impl<T: Pod> hash::Hash for ByteKey<T> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        state.write(self.as_bytes());
+    }
+}
+
+unsafe impl<T: Pod> crate::traits::StableLayout for ByteKey<T> {}
+unsafe impl<T: Pod> crate::traits::RawConvert for ByteKey<T> {}
+unsafe impl<T: Pod> Pod for ByteKey<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::hash::{Hash, Hasher};
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct Generated {
+        id: u32,
+        flags: [u8; 4],
+    }
+
+    unsafe impl crate::traits::StableLayout for Generated {}
+    unsafe impl crate::traits::RawConvert for Generated {}
+    unsafe impl Pod for Generated {}
+
+    #[test]
+    fn fnv_known_vectors() {
+        const HASH: u64 = fnv1a64(b"briny");
+
+        assert_eq!(fnv1a64(b""), 0xCBF2_9CE4_8422_2325);
+        assert_eq!(fnv1a64(b"a"), 0xAF63_DC4C_8601_EC8C);
+        assert_eq!(fnv1a64(b"foobar"), 0x8594_4171_F739_67E8);
+
+        let mut hasher = Fnv1a64::default();
+        hasher.write(b"bri");
+        hasher.write(b"ny");
+        assert_eq!(hasher.finish(), HASH);
+    }
+
+    #[test]
+    fn byte_identity() {
+        let a = Generated {
+            id: 1,
+            flags: [0; 4],
+        };
+        let b = Generated {
+            id: 1,
+            flags: [0, 0, 0, 1],
+        };
+
+        assert!(bytes_eq(&a, &a));
+        assert!(!bytes_eq(&a, &b));
+        assert_eq!(bytes_cmp(&a, &b), Ordering::Less);
+        assert!(ByteKey(a) < ByteKey(b));
+        assert!(
+            ByteKey(a)
+                == ByteKey(Generated {
+                    id: 1,
+                    flags: [0; 4]
+                })
+        );
+        assert_eq!(ByteKey(a).stable_hash(), pod_hash(&a));
+
+        let mut hasher = Fnv1a64::default();
+        ByteKey(a).hash(&mut hasher);
+        assert_eq!(hasher.finish(), pod_hash(&a));
+    }
+}
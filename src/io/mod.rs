@@ -0,0 +1,17 @@
+//! Cursors for reading and writing [`Pod`] values sequentially over byte buffers.
+//!
+//! With the `std` feature, [`Pod`] values can also be streamed through
+//! `std::io::Read` and `std::io::Write` without an intermediate buffer.
+//!
+//! [`Pod`]: crate::traits::Pod
+
+mod reader;
+pub use reader::ByteReader;
+
+mod writer;
+pub use writer::ByteWriter;
+
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+pub use stream::{read_pod, read_pod_slice, write_pod, write_pod_slice};
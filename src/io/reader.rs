@@ -0,0 +1,235 @@
+use crate::{
+    raw,
+    traits::{InteriorImmutable, Pod},
+    BrinyError,
+};
+
+/// A cursor that parses [`Pod`] values out of a byte slice in order.
+///
+/// Every read advances the cursor past the bytes it consumed. A failed read
+/// leaves the cursor where it was and records the offset it failed at, which
+/// can be retrieved with [`ByteReader::error_position`].
+#[derive(Debug, Clone)]
+pub struct ByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    error_position: Option<usize>,
+}
+
+impl<'a> ByteReader<'a> {
+    /// Creates a reader at the start of `bytes`.
+    #[inline]
+    #[must_use]
+    pub const fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            error_position: None,
+        }
+    }
+
+    /// The number of bytes consumed so far.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The number of bytes left to read.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    /// The bytes left to read.
+    #[inline]
+    #[must_use]
+    pub fn rest(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    /// The offset at which the most recent failed read occurred, if any.
+    #[inline]
+    #[must_use]
+    pub const fn error_position(&self) -> Option<usize> {
+        self.error_position
+    }
+
+    /// Records a failure at the current position.
+    #[inline]
+    const fn fail<T>(&mut self, err: BrinyError) -> Result<T, BrinyError> {
+        self.error_position = Some(self.position);
+        Err(err)
+    }
+
+    /// Advances past `len` bytes that have already been checked.
+    #[inline]
+    const fn advance(&mut self, len: usize) {
+        self.position += len;
+    }
+
+    /// Copies the next value out of the buffer, which must be aligned for `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if fewer than `size_of::<T>()`
+    /// bytes remain, and [`BrinyError::UNALIGNED_ACCESS`] if the current position
+    /// is not aligned for `T`.
+    #[inline]
+    pub fn read<T: Pod>(&mut self) -> Result<T, BrinyError> {
+        match raw::from_prefix::<T>(self.rest()) {
+            Ok((value, _)) => {
+                self.advance(size_of::<T>());
+                Ok(value)
+            }
+            Err(err) => self.fail(err),
+        }
+    }
+
+    /// Borrows the next value from the buffer without copying it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if fewer than `size_of::<T>()`
+    /// bytes remain, and [`BrinyError::UNALIGNED_ACCESS`] if the current position
+    /// is not aligned for `T`.
+    #[inline]
+    pub fn read_ref<T: Pod + InteriorImmutable>(&mut self) -> Result<&'a T, BrinyError> {
+        match raw::ref_from_prefix::<T>(self.rest()) {
+            Ok((value, _)) => {
+                self.advance(size_of::<T>());
+                Ok(value)
+            }
+            Err(err) => self.fail(err),
+        }
+    }
+
+    /// Borrows the next `count` values from the buffer without copying them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if fewer than
+    /// `count * size_of::<T>()` bytes remain, and [`BrinyError::UNALIGNED_ACCESS`]
+    /// if the current position is not aligned for `T`.
+    #[inline]
+    pub fn read_slice<T: Pod + InteriorImmutable>(
+        &mut self,
+        count: usize,
+    ) -> Result<&'a [T], BrinyError> {
+        match raw::slice_from_prefix::<T>(self.rest(), count) {
+            Ok((values, _)) => {
+                self.advance(size_of_val(values));
+                Ok(values)
+            }
+            Err(err) => self.fail(err),
+        }
+    }
+
+    /// Copies the next value out of the buffer regardless of its alignment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if fewer than `size_of::<T>()`
+    /// bytes remain.
+    #[inline]
+    pub fn read_unaligned<T: Pod>(&mut self) -> Result<T, BrinyError> {
+        let Some(bytes) = self.rest().get(..size_of::<T>()) else {
+            return self.fail(BrinyError::SIZE_BOUND_FAILURE);
+        };
+        match raw::from_bytes_unaligned::<T>(bytes) {
+            Ok(value) => {
+                self.advance(size_of::<T>());
+                Ok(value)
+            }
+            Err(err) => self.fail(err),
+        }
+    }
+
+    /// Copies the next value out of the buffer without advancing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if fewer than `size_of::<T>()`
+    /// bytes remain, and [`BrinyError::UNALIGNED_ACCESS`] if the current position
+    /// is not aligned for `T`.
+    #[inline]
+    pub fn peek<T: Pod>(&mut self) -> Result<T, BrinyError> {
+        match raw::from_prefix::<T>(self.rest()) {
+            Ok((value, _)) => Ok(value),
+            Err(err) => self.fail(err),
+        }
+    }
+
+    /// Advances past the next `len` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if fewer than `len` bytes remain.
+    #[inline]
+    pub const fn skip(&mut self, len: usize) -> Result<(), BrinyError> {
+        if len > self.remaining() {
+            return self.fail(BrinyError::SIZE_BOUND_FAILURE);
+        }
+        self.advance(len);
+        Ok(())
+    }
+
+    /// Advances until the position is a multiple of `align`.
+    ///
+    /// The position is relative to the start of the buffer, so the resulting
+    /// address is only a multiple of `align` if the buffer itself is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if `align` is zero or the
+    /// padding runs past the end of the buffer.
+    #[inline]
+    pub const fn align_to(&mut self, align: usize) -> Result<(), BrinyError> {
+        match self.position.checked_next_multiple_of(align) {
+            Some(aligned) => self.skip(aligned - self.position),
+            None => self.fail(BrinyError::SIZE_BOUND_FAILURE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raw::slice_to_bytes;
+
+    #[test]
+    fn sequential_reads() {
+        let words = [0x0102_0304u32, 5, 6, 7];
+        let mut reader = ByteReader::new(slice_to_bytes(&words));
+
+        assert_eq!(reader.read::<u32>().unwrap(), 0x0102_0304);
+        assert_eq!(reader.peek::<u32>().unwrap(), 5);
+        assert_eq!(*reader.read_ref::<u32>().unwrap(), 5);
+        assert_eq!(reader.read_slice::<u32>(2).unwrap(), &[6, 7]);
+        assert_eq!(reader.position(), 16);
+        assert_eq!(reader.remaining(), 0);
+    }
+
+    #[test]
+    fn failures_record_position() {
+        let words = [1u32, 2];
+        let mut reader = ByteReader::new(slice_to_bytes(&words));
+
+        reader.skip(1).unwrap();
+        assert!(reader.read::<u16>().unwrap_err().is_unaligned_access());
+        assert_eq!(reader.error_position(), Some(1));
+        assert_eq!(reader.position(), 1);
+
+        assert_eq!(reader.read_unaligned::<u16>().unwrap(), u16::from_ne_bytes([0, 0]));
+        reader.align_to(4).unwrap();
+        assert_eq!(reader.position(), 4);
+
+        assert!(reader.read::<u64>().unwrap_err().is_size_bound_failure());
+        assert_eq!(reader.error_position(), Some(4));
+        assert!(reader.skip(5).is_err());
+        assert!(reader.align_to(0).unwrap_err().is_size_bound_failure());
+        assert!(reader.align_to(usize::MAX).is_err());
+        assert_eq!(reader.position(), 4);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::{
+    raw,
+    traits::{Pod, Zeroable},
+    BrinyError,
+};
+use std::io::{self, Read, Write};
+
+/// Reads one [`Pod`] value from `reader`, straight into the value's own bytes.
+///
+/// # Errors
+///
+/// Returns any error of [`Read::read_exact`], including
+/// [`io::ErrorKind::UnexpectedEof`] if the stream ends early.
+#[inline]
+pub fn read_pod<T: Pod, R: Read + ?Sized>(reader: &mut R) -> io::Result<T> {
+    let mut value = T::zeroed();
+    reader.read_exact(raw::to_bytes_mut(&mut value))?;
+    Ok(value)
+}
+
+/// Fills `values` with [`Pod`] values read from `reader`.
+///
+/// # Errors
+///
+/// Returns any error of [`Read::read_exact`]. The contents of `values` are
+/// unspecified after an error.
+#[inline]
+pub fn read_pod_slice<T: Pod, R: Read + ?Sized>(
+    reader: &mut R,
+    values: &mut [T],
+) -> io::Result<()> {
+    reader.read_exact(raw::slice_to_bytes_mut(values))
+}
+
+/// Writes the bytes of one [`Pod`] value to `writer`.
+///
+/// # Errors
+///
+/// Returns any error of [`Write::write_all`].
+#[inline]
+pub fn write_pod<T: Pod, W: Write + ?Sized>(writer: &mut W, value: &T) -> io::Result<()> {
+    writer.write_all(raw::to_bytes(value))
+}
+
+/// Writes the bytes of a slice of [`Pod`] values to `writer`.
+///
+/// # Errors
+///
+/// Returns any error of [`Write::write_all`].
+#[inline]
+pub fn write_pod_slice<T: Pod, W: Write + ?Sized>(writer: &mut W, values: &[T]) -> io::Result<()> {
+    writer.write_all(raw::slice_to_bytes(values))
+}
+
+impl From<BrinyError> for io::Error {
+    /// Errors about the contents of the data become [`io::ErrorKind::InvalidData`],
+    /// everything else [`io::ErrorKind::InvalidInput`].
+    fn from(err: BrinyError) -> Self {
+        let data = BrinyError::INVALID_BITPATTERN
+            | BrinyError::CHECKSUM_MISMATCH
+            | BrinyError::INVALID_UTF8
+            | BrinyError::VERSION_MISMATCH;
+        let kind = if (err & data).is_err() {
+            io::ErrorKind::InvalidData
+        } else {
+            io::ErrorKind::InvalidInput
+        };
+        Self::new(kind, err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    #[test]
+    fn roundtrip_through_streams() {
+        let mut out = Vec::new();
+        write_pod(&mut out, &0x0102_0304u32).unwrap();
+        write_pod_slice(&mut out, &[5u16, 6]).unwrap();
+        assert_eq!(out.len(), 8);
+
+        let mut input = out.as_slice();
+        assert_eq!(read_pod::<u32, _>(&mut input).unwrap(), 0x0102_0304);
+        let mut pair = [0u16; 2];
+        read_pod_slice(&mut input, &mut pair).unwrap();
+        assert_eq!(pair, [5, 6]);
+
+        let err = read_pod::<u8, _>(&mut input).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn briny_errors_convert() {
+        let err = io::Error::from(BrinyError::INVALID_BITPATTERN | BrinyError::BAD_BUFFER);
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            io::Error::from(BrinyError::UNALIGNED_ACCESS).kind(),
+            io::ErrorKind::InvalidInput
+        );
+
+        let inner = err.into_inner().unwrap();
+        assert_eq!(
+            *inner.downcast::<BrinyError>().unwrap(),
+            BrinyError::INVALID_BITPATTERN | BrinyError::BAD_BUFFER
+        );
+    }
+}
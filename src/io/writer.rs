@@ -0,0 +1,187 @@
+use crate::{raw, traits::Pod, BrinyError};
+
+/// A cursor that serializes [`Pod`] values into a caller-provided byte buffer.
+///
+/// Writes never allocate; running out of space reports
+/// [`BrinyError::BAD_BUFFER`] and leaves the buffer untouched.
+///
+/// Fields that are only known later (such as a length prefix) can be reserved
+/// up front and patched once the rest has been written:
+///
+/// ```
+/// use briny::io::ByteWriter;
+///
+/// let mut buf = [0u32; 4];
+/// let mut writer = ByteWriter::new(briny::raw::slice_to_bytes_mut(&mut buf));
+///
+/// let len_at = writer.position();
+/// *writer.reserve::<u32>()? = 0;
+/// writer.write_slice(&[7u16, 8, 9])?;
+/// *writer.get_mut::<u32>(len_at)? = 6;
+///
+/// assert_eq!(writer.finish().len(), 10);
+/// # Ok::<(), briny::BrinyError>(())
+/// ```
+#[derive(Debug)]
+pub struct ByteWriter<'a> {
+    buf: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> ByteWriter<'a> {
+    /// Creates a writer at the start of `buf`.
+    #[inline]
+    #[must_use]
+    pub const fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, position: 0 }
+    }
+
+    /// The number of bytes written so far.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The number of bytes that can still be written.
+    #[inline]
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.buf.len() - self.position
+    }
+
+    /// Claims the next `len` bytes, or reports that they do not fit.
+    #[inline]
+    fn claim(&mut self, len: usize) -> Result<&mut [u8], BrinyError> {
+        if len > self.remaining() {
+            return Err(BrinyError::BAD_BUFFER);
+        }
+        let start = self.position;
+        self.position += len;
+        Ok(&mut self.buf[start..start + len])
+    }
+
+    /// Appends the bytes of `value`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::BAD_BUFFER`] if fewer than `size_of::<T>()` bytes
+    /// remain.
+    #[inline]
+    pub fn write<T: Pod>(&mut self, value: &T) -> Result<(), BrinyError> {
+        self.write_slice(core::slice::from_ref(value))
+    }
+
+    /// Appends the bytes of every value in `values`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::BAD_BUFFER`] if the values do not fit in the
+    /// remaining space.
+    #[inline]
+    pub fn write_slice<T: Pod>(&mut self, values: &[T]) -> Result<(), BrinyError> {
+        let bytes = raw::slice_to_bytes(values);
+        self.claim(bytes.len())?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes zeros until the position is a multiple of `align`.
+    ///
+    /// The position is relative to the start of the buffer, so the resulting
+    /// address is only a multiple of `align` if the buffer itself is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::BAD_BUFFER`] if `align` is zero or the padding
+    /// does not fit in the remaining space.
+    #[inline]
+    pub fn pad_to_align(&mut self, align: usize) -> Result<(), BrinyError> {
+        let Some(aligned) = self.position.checked_next_multiple_of(align) else {
+            return Err(BrinyError::BAD_BUFFER);
+        };
+        self.claim(aligned - self.position)?.fill(0);
+        Ok(())
+    }
+
+    /// Claims space for a `T`, zeroes it, and returns it for filling in.
+    ///
+    /// The value stays part of the output; use [`ByteWriter::get_mut`] with the
+    /// position it was reserved at to patch it after more has been written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::UNALIGNED_ACCESS`] if the current position is not
+    /// aligned for `T`, and [`BrinyError::BAD_BUFFER`] if fewer than
+    /// `size_of::<T>()` bytes remain.
+    #[inline]
+    pub fn reserve<T: Pod>(&mut self) -> Result<&mut T, BrinyError> {
+        let start = self.position;
+        if (self.buf[start..].as_ptr() as usize) % align_of::<T>() != 0 {
+            return Err(BrinyError::UNALIGNED_ACCESS);
+        }
+        let bytes = self.claim(size_of::<T>())?;
+        bytes.fill(0);
+        raw::mut_from_bytes(bytes)
+    }
+
+    /// Borrows an already written `T` at `offset` for back-patching.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the value would extend past
+    /// the written bytes, and [`BrinyError::UNALIGNED_ACCESS`] if `offset` is not
+    /// aligned for `T`.
+    #[inline]
+    pub fn get_mut<T: Pod>(&mut self, offset: usize) -> Result<&mut T, BrinyError> {
+        let Some(bytes) = self.buf[..self.position].get_mut(offset..) else {
+            return Err(BrinyError::SIZE_BOUND_FAILURE);
+        };
+        raw::mut_from_prefix(bytes).map(|(value, _)| value)
+    }
+
+    /// Consumes the writer and returns the bytes written so far.
+    #[inline]
+    #[must_use]
+    pub fn finish(self) -> &'a [u8] {
+        &self.buf[..self.position]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::ByteReader;
+
+    #[test]
+    fn writes_roundtrip_through_reader() {
+        let mut buf = [0u64; 4];
+        let mut writer = ByteWriter::new(raw::slice_to_bytes_mut(&mut buf));
+
+        writer.write(&0xAAu8).unwrap();
+        writer.pad_to_align(4).unwrap();
+        writer.write(&0x1234_5678u32).unwrap();
+        writer.write_slice(&[1u16, 2, 3]).unwrap();
+        assert_eq!(writer.position(), 14);
+
+        let mut reader = ByteReader::new(writer.finish());
+        assert_eq!(reader.read::<u8>().unwrap(), 0xAA);
+        reader.align_to(4).unwrap();
+        assert_eq!(reader.read::<u32>().unwrap(), 0x1234_5678);
+        assert_eq!(reader.read_slice::<u16>(3).unwrap(), &[1, 2, 3]);
+    }
+
+    #[test]
+    fn capacity_is_enforced() {
+        let mut buf = [0u8; 6];
+        let mut writer = ByteWriter::new(&mut buf);
+
+        writer.write(&1u32).unwrap();
+        assert!(writer.write(&2u32).unwrap_err().is_bad_buffer());
+        assert_eq!(writer.position(), 4);
+        assert!(writer.pad_to_align(8).unwrap_err().is_bad_buffer());
+        assert!(writer.pad_to_align(0).unwrap_err().is_bad_buffer());
+        assert!(writer.pad_to_align(usize::MAX).unwrap_err().is_bad_buffer());
+        assert!(writer.get_mut::<u32>(2).unwrap_err().is_size_bound_failure());
+        assert_eq!(writer.remaining(), 2);
+    }
+}
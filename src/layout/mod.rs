@@ -0,0 +1,497 @@
+//! Compile-time descriptions of type layouts.
+//!
+//! [`TypeLayout`] exposes the size, alignment and per-field offsets of a type
+//! as constants, e.g. to compare them against the headers of another language
+//! in a test. It is implemented for the primitives, atomics, raw pointers,
+//! arrays and transparent wrappers of `core`, and can be derived for
+//! `#[repr(C)]` structs with the `derive` feature:
+//!
+//! ```
+//! # #[cfg(feature = "derive")] {
+//! use briny::layout::TypeLayout;
+//! use briny::traits::StableLayout;
+//!
+//! #[derive(TypeLayout, StableLayout)]
+//! #[repr(C)]
+//! struct Sample {
+//!     tag: u8,
+//!     value: u32,
+//! }
+//!
+//! assert_eq!(Sample::FIELDS[1].name(), "value");
+//! assert_eq!(Sample::FIELDS[1].offset(), 4);
+//! assert!(Sample::has_padding());
+//! # }
+//! ```
+//!
+//! [`TypeLayout::LAYOUT_HASH`] condenses a layout into a fingerprint that can
+//! be stored next to persisted values, and [`Versioned`] is a header carrying
+//! it so that [`from_bytes_versioned`] refuses records written by a different
+//! version of the type.
+
+use crate::{
+    endian::{ByteOrder, F32, F64, I128, I16, I32, I64, U128, U16, U32, U64},
+    hash::{fnv1a64, fnv1a64_update},
+    traits::StableLayout,
+};
+use core::{
+    cell::{Cell, UnsafeCell},
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+    num::{
+        NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+        NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+    },
+    sync::atomic::{
+        AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+        AtomicU64, AtomicU8, AtomicUsize,
+    },
+};
+
+#[cfg(feature = "derive")]
+pub use briny_derive::TypeLayout;
+
+mod versioned;
+pub use versioned::{from_bytes_versioned, write_versioned, Versioned};
+
+/// The position and extent of one field within a type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldLayout {
+    name: &'static str,
+    ty: &'static str,
+    offset: usize,
+    size: usize,
+    align: usize,
+    layout_hash: u64,
+}
+
+impl FieldLayout {
+    /// Describes the field `name` of type `ty`.
+    ///
+    /// The layout of `ty` itself is unknown here, so its fingerprint only
+    /// covers the spelling of `ty`, `size` and `align`, not its byte order.
+    /// It never equals the fingerprint [`FieldLayout::of`] records for the
+    /// same field, so a type hashes differently depending on which of the two
+    /// its impl uses. Prefer [`FieldLayout::of`] for types implementing
+    /// [`TypeLayout`].
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        name: &'static str,
+        ty: &'static str,
+        offset: usize,
+        size: usize,
+        align: usize,
+    ) -> Self {
+        Self {
+            name,
+            ty,
+            offset,
+            size,
+            align,
+            layout_hash: hash_usize(hash_usize(fnv1a64(ty.as_bytes()), size), align),
+        }
+    }
+
+    /// Describes the field `name` of type `T`, as written `ty`, at `offset`.
+    ///
+    /// The fingerprint of the field is [`TypeLayout::LAYOUT_HASH`] of `T`.
+    #[inline]
+    #[must_use]
+    pub const fn of<T: TypeLayout>(name: &'static str, ty: &'static str, offset: usize) -> Self {
+        Self {
+            name,
+            ty,
+            offset,
+            size: T::SIZE,
+            align: T::ALIGN,
+            layout_hash: T::LAYOUT_HASH,
+        }
+    }
+
+    /// The name of the field, or its index for tuple structs.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The type of the field as written in the source.
+    #[inline]
+    #[must_use]
+    pub const fn ty(&self) -> &'static str {
+        self.ty
+    }
+
+    /// The offset of the field from the start of the type, in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The size of the field in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The alignment of the field in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn align(&self) -> usize {
+        self.align
+    }
+
+    /// The [`TypeLayout::LAYOUT_HASH`] of the field's type.
+    #[inline]
+    #[must_use]
+    pub const fn layout_hash(&self) -> u64 {
+        self.layout_hash
+    }
+}
+
+/// The name and discriminant of one variant of a fieldless enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantLayout {
+    name: &'static str,
+    discriminant: i128,
+}
+
+impl VariantLayout {
+    /// Describes the variant `name` with the value `discriminant`.
+    #[inline]
+    #[must_use]
+    pub const fn new(name: &'static str, discriminant: i128) -> Self {
+        Self { name, discriminant }
+    }
+
+    /// The name of the variant.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The discriminant of the variant.
+    #[inline]
+    #[must_use]
+    pub const fn discriminant(&self) -> i128 {
+        self.discriminant
+    }
+}
+
+/// A type whose layout is described at compile time.
+///
+/// Implementations should be derived; a hand-written [`TypeLayout::FIELDS`]
+/// that does not match the type makes every answer of this trait wrong.
+pub trait TypeLayout: StableLayout + Sized {
+    /// The size of the type in bytes.
+    const SIZE: usize = size_of::<Self>();
+
+    /// The alignment of the type in bytes.
+    const ALIGN: usize = align_of::<Self>();
+
+    /// Every field in declaration order, or none for primitives and enums.
+    const FIELDS: &'static [FieldLayout];
+
+    /// Every variant in declaration order, or none for anything but enums.
+    const VARIANTS: &'static [VariantLayout] = &[];
+
+    /// A fingerprint of the layout, stable across builds and compilers.
+    ///
+    /// It covers the size and alignment of the type, the name, offset, size
+    /// and fingerprint of every field and the name and discriminant of every
+    /// variant, but not the names of the type or of its field types, so
+    /// renaming them keeps it intact. Only native-endian numbers, including
+    /// enum discriminants, depend on the byte order of the target, so targets
+    /// only disagree on it where the layout itself differs.
+    const LAYOUT_HASH: u64 = {
+        let seed = if Self::VARIANTS.is_empty() {
+            fnv1a64(b"")
+        } else {
+            NATIVE_ORDER
+        };
+        let mut hash = hash_usize(hash_usize(seed, Self::SIZE), Self::ALIGN);
+        let mut i = 0;
+        while i < Self::FIELDS.len() {
+            let field = &Self::FIELDS[i];
+            hash = fnv1a64_update(hash, field.name.as_bytes());
+            hash = hash_usize(hash_usize(hash, field.offset), field.size);
+            hash = fnv1a64_update(hash, &field.layout_hash.to_le_bytes());
+            i += 1;
+        }
+        let mut i = 0;
+        while i < Self::VARIANTS.len() {
+            let variant = &Self::VARIANTS[i];
+            hash = fnv1a64_update(hash, variant.name.as_bytes());
+            hash = fnv1a64_update(hash, &variant.discriminant.to_le_bytes());
+            i += 1;
+        }
+        hash
+    };
+
+    /// Whether any byte of the type is padding.
+    ///
+    /// The default only looks for gaps between [`TypeLayout::FIELDS`], not for
+    /// padding nested inside the fields. Derived impls and the impls for
+    /// arrays and wrappers also ask their fields, and hand-written impls with
+    /// padded fields should do the same.
+    #[inline]
+    #[must_use]
+    fn has_padding() -> bool {
+        has_padding(Self::SIZE, Self::FIELDS)
+    }
+}
+
+/// Whether `fields` leave any of `size` bytes uncovered.
+///
+/// Only the fields themselves are considered, not padding nested inside them.
+#[inline]
+#[must_use]
+pub const fn has_padding(size: usize, fields: &[FieldLayout]) -> bool {
+    if fields.is_empty() {
+        return false;
+    }
+    let mut covered = 0;
+    let mut i = 0;
+    while i < fields.len() {
+        covered += fields[i].size;
+        i += 1;
+    }
+    covered != size
+}
+
+/// The byte order of the target, which the layout of every number depends on.
+const NATIVE_ORDER: u64 = fnv1a64(if cfg!(target_endian = "little") {
+    b"little"
+} else {
+    b"big"
+});
+
+/// Continues a hash with a `usize`, encoded the same way on every target.
+#[inline]
+const fn hash_usize(hash: u64, value: usize) -> u64 {
+    fnv1a64_update(hash, &(value as u64).to_le_bytes())
+}
+
+macro_rules! primitive_layout {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl TypeLayout for $t {
+                const FIELDS: &'static [FieldLayout] = &[];
+                const LAYOUT_HASH: u64 = hash_usize(
+                    fnv1a64_update(NATIVE_ORDER, stringify!($t).as_bytes()),
+                    Self::SIZE,
+                );
+            }
+        )*
+    };
+}
+
+primitive_layout!(
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64, bool, char,
+    NonZeroU8, NonZeroI8, NonZeroU16, NonZeroI16, NonZeroU32, NonZeroI32, NonZeroU64, NonZeroI64,
+    NonZeroU128, NonZeroI128, NonZeroUsize, NonZeroIsize, AtomicU8, AtomicI8, AtomicU16, AtomicI16,
+    AtomicU32, AtomicI32, AtomicU64, AtomicI64, AtomicUsize, AtomicIsize, AtomicBool,
+);
+
+impl<T: 'static> TypeLayout for *const T {
+    const FIELDS: &'static [FieldLayout] = &[];
+    const LAYOUT_HASH: u64 = hash_usize(fnv1a64_update(NATIVE_ORDER, b"*const"), Self::SIZE);
+}
+
+impl<T: 'static> TypeLayout for *mut T {
+    const FIELDS: &'static [FieldLayout] = &[];
+    const LAYOUT_HASH: u64 = hash_usize(fnv1a64_update(NATIVE_ORDER, b"*mut"), Self::SIZE);
+}
+
+impl<T: 'static> TypeLayout for PhantomData<T> {
+    const FIELDS: &'static [FieldLayout] = &[];
+}
+
+macro_rules! transparent_layout {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<T: TypeLayout> TypeLayout for $name<T> {
+                const FIELDS: &'static [FieldLayout] = &[];
+                const LAYOUT_HASH: u64 = T::LAYOUT_HASH;
+
+                #[inline]
+                fn has_padding() -> bool {
+                    T::has_padding()
+                }
+            }
+        )*
+    };
+}
+
+transparent_layout!(Wrapping, ManuallyDrop, MaybeUninit, Cell, UnsafeCell);
+
+macro_rules! endian_layout {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<O: ByteOrder> TypeLayout for $name<O> {
+                const FIELDS: &'static [FieldLayout] = &[];
+                const LAYOUT_HASH: u64 = hash_usize(
+                    fnv1a64_update(
+                        fnv1a64(stringify!($name).as_bytes()),
+                        if O::IS_LITTLE { b"little" } else { b"big" },
+                    ),
+                    Self::SIZE,
+                );
+            }
+        )*
+    };
+}
+
+endian_layout!(U16, U32, U64, U128, I16, I32, I64, I128, F32, F64);
+
+impl<T: TypeLayout, const N: usize> TypeLayout for [T; N] {
+    const FIELDS: &'static [FieldLayout] = &[];
+    const LAYOUT_HASH: u64 = hash_usize(fnv1a64_update(T::LAYOUT_HASH, b"[]"), N);
+
+    #[inline]
+    fn has_padding() -> bool {
+        N > 0 && T::has_padding()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(C)]
+    struct Manual {
+        tag: u16,
+        value: u64,
+    }
+
+    unsafe impl StableLayout for Manual {}
+
+    impl TypeLayout for Manual {
+        const FIELDS: &'static [FieldLayout] = &[
+            FieldLayout::new("tag", "u16", core::mem::offset_of!(Self, tag), 2, 2),
+            FieldLayout::new("value", "u64", core::mem::offset_of!(Self, value), 8, 8),
+        ];
+    }
+
+    #[test]
+    fn primitive_and_array_layouts() {
+        assert_eq!(u32::SIZE, 4);
+        assert_eq!(<[u16; 3]>::SIZE, 6);
+        assert_eq!(<[u16; 3]>::ALIGN, 2);
+        assert!(u64::FIELDS.is_empty());
+        assert!(!<[char; 4]>::has_padding());
+    }
+
+    #[test]
+    fn wrapper_layouts() {
+        assert_eq!(AtomicU64::SIZE, 8);
+        assert_eq!(<*const u8>::SIZE, size_of::<usize>());
+        assert_eq!(PhantomData::<u64>::SIZE, 0);
+        assert_eq!(Wrapping::<u32>::LAYOUT_HASH, u32::LAYOUT_HASH);
+        assert_eq!(MaybeUninit::<Manual>::LAYOUT_HASH, Manual::LAYOUT_HASH);
+        assert!(ManuallyDrop::<Manual>::has_padding());
+        assert_ne!(AtomicU32::LAYOUT_HASH, u32::LAYOUT_HASH);
+        assert_ne!(<*const u8>::LAYOUT_HASH, <*mut u8>::LAYOUT_HASH);
+    }
+
+    #[test]
+    fn struct_layouts() {
+        assert_eq!(Manual::SIZE, 16);
+        assert_eq!(Manual::FIELDS[1].offset(), 8);
+        assert_eq!(Manual::FIELDS[1].ty(), "u64");
+        assert!(Manual::has_padding());
+        assert!(<[Manual; 2]>::has_padding());
+    }
+
+    #[test]
+    fn layout_hashes() {
+        use crate::endian::{BigEndian, LittleEndian};
+
+        assert_ne!(u32::LAYOUT_HASH, i32::LAYOUT_HASH);
+        assert_ne!(u32::LAYOUT_HASH, f32::LAYOUT_HASH);
+        assert_ne!(<[u8; 4]>::LAYOUT_HASH, <[u8; 5]>::LAYOUT_HASH);
+        assert_ne!(<[u8; 4]>::LAYOUT_HASH, u32::LAYOUT_HASH);
+        assert_ne!(
+            U32::<LittleEndian>::LAYOUT_HASH,
+            U32::<BigEndian>::LAYOUT_HASH
+        );
+        assert_eq!(
+            Manual::FIELDS[0].layout_hash(),
+            FieldLayout::new("x", "u16", 0, 2, 2).layout_hash()
+        );
+        assert_ne!(
+            Manual::FIELDS[0].layout_hash(),
+            FieldLayout::of::<u16>("tag", "u16", 0).layout_hash()
+        );
+        assert_ne!(Manual::LAYOUT_HASH, <[Manual; 1]>::LAYOUT_HASH);
+    }
+
+    #[test]
+    fn portable_layout_hashes() {
+        use crate::endian::LittleEndian;
+        use core::mem::offset_of;
+
+        #[repr(C)]
+        struct Portable {
+            id: U32<LittleEndian>,
+            len: U16<LittleEndian>,
+        }
+
+        unsafe impl StableLayout for Portable {}
+
+        impl TypeLayout for Portable {
+            const FIELDS: &'static [FieldLayout] = &[
+                FieldLayout::of::<U32<LittleEndian>>("id", "U32", offset_of!(Self, id)),
+                FieldLayout::of::<U16<LittleEndian>>("len", "U16", offset_of!(Self, len)),
+            ];
+        }
+
+        // Computed on a little-endian target; big-endian targets must agree.
+        assert_eq!(Portable::LAYOUT_HASH, 0xBEBF_51BC_47F7_8ED9);
+    }
+
+    #[test]
+    fn variant_hashes() {
+        #[repr(u8)]
+        #[derive(Clone, Copy)]
+        enum Mode {
+            Read = 1,
+            Write = 2,
+        }
+
+        unsafe impl StableLayout for Mode {}
+
+        impl TypeLayout for Mode {
+            const FIELDS: &'static [FieldLayout] = &[];
+            const VARIANTS: &'static [VariantLayout] = &[
+                VariantLayout::new("Read", Self::Read as i128),
+                VariantLayout::new("Write", Self::Write as i128),
+            ];
+        }
+
+        #[repr(u8)]
+        #[derive(Clone, Copy)]
+        enum Renumbered {
+            Read = 1,
+            Write = 3,
+        }
+
+        unsafe impl StableLayout for Renumbered {}
+
+        impl TypeLayout for Renumbered {
+            const FIELDS: &'static [FieldLayout] = &[];
+            const VARIANTS: &'static [VariantLayout] = &[
+                VariantLayout::new("Read", Self::Read as i128),
+                VariantLayout::new("Write", Self::Write as i128),
+            ];
+        }
+
+        assert_eq!(Mode::VARIANTS[1].discriminant(), 2);
+        assert_ne!(Mode::LAYOUT_HASH, Renumbered::LAYOUT_HASH);
+        assert!(!Mode::has_padding());
+    }
+}
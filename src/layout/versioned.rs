@@ -0,0 +1,256 @@
+use super::TypeLayout;
+use crate::{
+    endian::{LittleEndian, U32, U64},
+    raw,
+    traits::{InteriorImmutable, Pod, RawConvert, StableLayout, Unaligned},
+    BrinyError,
+};
+use core::{fmt, marker::PhantomData};
+
+/// A header stored in front of a persisted `T`.
+///
+/// It records [`TypeLayout::LAYOUT_HASH`] of `T` at the time of writing, so a
+/// record written before a field was added, removed, reordered or resized is
+/// rejected by [`from_bytes_versioned`] instead of being misread. All fields
+/// are little-endian and unaligned, so the header reads the same everywhere.
+///
+/// ```
+/// use briny::layout::{from_bytes_versioned, write_versioned, Versioned};
+/// use briny::BrinyError;
+///
+/// let mut buf = [0u8; size_of::<Versioned<u32>>() + 4];
+/// write_versioned(&0xC0FF_EEu32, &mut buf).unwrap();
+///
+/// assert_eq!(from_bytes_versioned::<u32>(&buf), Ok(0xC0FF_EE));
+/// assert_eq!(
+///     from_bytes_versioned::<i32>(&buf),
+///     Err(BrinyError::VERSION_MISMATCH)
+/// );
+/// ```
+#[repr(C)]
+pub struct Versioned<T> {
+    magic: U32<LittleEndian>,
+    layout_hash: U64<LittleEndian>,
+    len: U64<LittleEndian>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: TypeLayout> Versioned<T> {
+    /// The magic number opening every header, `"BRNY"` in ASCII.
+    pub const MAGIC: u32 = u32::from_le_bytes(*b"BRNY");
+
+    /// A header for one `T` following it.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            magic: U32::new(Self::MAGIC),
+            layout_hash: U64::new(T::LAYOUT_HASH),
+            len: U64::new(T::SIZE as u64),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The stored magic number.
+    #[inline]
+    #[must_use]
+    pub const fn magic(&self) -> u32 {
+        self.magic.get()
+    }
+
+    /// The stored layout fingerprint.
+    #[inline]
+    #[must_use]
+    pub const fn layout_hash(&self) -> u64 {
+        self.layout_hash.get()
+    }
+
+    /// The stored length of the value following the header, in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn value_len(&self) -> u64 {
+        self.len.get()
+    }
+
+    /// Checks that the header describes a `T` in the layout compiled now.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BrinyError::BAD_BUFFER`] if the magic number is wrong,
+    /// [`BrinyError::VERSION_MISMATCH`] if the layout fingerprint differs, and
+    /// [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not `size_of::<T>()`.
+    #[inline]
+    pub const fn check(&self) -> Result<(), BrinyError> {
+        if self.magic() != Self::MAGIC {
+            return Err(BrinyError::BAD_BUFFER);
+        }
+        if self.layout_hash() != T::LAYOUT_HASH {
+            return Err(BrinyError::VERSION_MISMATCH);
+        }
+        if self.value_len() != T::SIZE as u64 {
+            return Err(BrinyError::SIZE_BOUND_FAILURE);
+        }
+        Ok(())
+    }
+}
+
+impl<T: TypeLayout> Default for Versioned<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for Versioned<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Versioned<T> {}
+
+impl<T> PartialEq for Versioned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.magic == other.magic && self.layout_hash == other.layout_hash && self.len == other.len
+    }
+}
+
+impl<T> Eq for Versioned<T> {}
+
+impl<T> fmt::Debug for Versioned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Versioned")
+            .field("magic", &self.magic)
+            .field("layout_hash", &self.layout_hash)
+            .field("len", &self.len)
+            .finish()
+    }
+}
+
+unsafe impl<T: 'static> StableLayout for Versioned<T> {}
+unsafe impl<T> RawConvert for Versioned<T> {}
+unsafe impl<T> InteriorImmutable for Versioned<T> {}
+unsafe impl<T> Unaligned for Versioned<T> {}
+unsafe impl<T: 'static> Pod for Versioned<T> {}
+
+/// Copies a `T` out of bytes starting with its [`Versioned`] header.
+///
+/// Neither the header nor the value needs to be aligned.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::VERSION_MISMATCH`] if the stored layout fingerprint
+/// is not the one of `T`, [`BrinyError::BAD_BUFFER`] if the bytes do not start
+/// with a header, and [`BrinyError::SIZE_BOUND_FAILURE`] if the length of the
+/// bytes does not match the header.
+#[inline]
+pub fn from_bytes_versioned<T: Pod + TypeLayout>(bytes: &[u8]) -> Result<T, BrinyError> {
+    let Some((header, value)) = bytes.split_at_checked(size_of::<Versioned<T>>()) else {
+        return Err(BrinyError::SIZE_BOUND_FAILURE);
+    };
+    raw::from_bytes_unaligned::<Versioned<T>>(header)?.check()?;
+    raw::from_bytes_unaligned(value)
+}
+
+/// Writes a [`Versioned`] header followed by `value` to the start of `out`,
+/// returning the number of bytes written.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if `out` is too short.
+#[inline]
+pub fn write_versioned<T: Pod + TypeLayout>(
+    value: &T,
+    out: &mut [u8],
+) -> Result<usize, BrinyError> {
+    let header = Versioned::<T>::new();
+    let (header_len, len) = (
+        size_of::<Versioned<T>>(),
+        size_of::<Versioned<T>>() + T::SIZE,
+    );
+    let Some(out) = out.get_mut(..len) else {
+        return Err(BrinyError::SIZE_BOUND_FAILURE);
+    };
+    out[..header_len].copy_from_slice(raw::to_bytes(&header));
+    out[header_len..].copy_from_slice(raw::to_bytes(value));
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::FieldLayout;
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct RecordV1 {
+        id: u32,
+        score: u32,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct RecordV2 {
+        score: u32,
+        id: u32,
+    }
+
+    unsafe impl StableLayout for RecordV1 {}
+    unsafe impl RawConvert for RecordV1 {}
+    unsafe impl Pod for RecordV1 {}
+
+    unsafe impl StableLayout for RecordV2 {}
+    unsafe impl RawConvert for RecordV2 {}
+    unsafe impl Pod for RecordV2 {}
+
+    impl TypeLayout for RecordV1 {
+        const FIELDS: &'static [FieldLayout] = &[
+            FieldLayout::of::<u32>("id", "u32", core::mem::offset_of!(Self, id)),
+            FieldLayout::of::<u32>("score", "u32", core::mem::offset_of!(Self, score)),
+        ];
+    }
+
+    impl TypeLayout for RecordV2 {
+        const FIELDS: &'static [FieldLayout] = &[
+            FieldLayout::of::<u32>("score", "u32", core::mem::offset_of!(Self, score)),
+            FieldLayout::of::<u32>("id", "u32", core::mem::offset_of!(Self, id)),
+        ];
+    }
+
+    #[test]
+    fn header_layout() {
+        let header = Versioned::<RecordV1>::new();
+        assert_eq!(size_of::<Versioned<RecordV1>>(), 20);
+        assert_eq!(&raw::to_bytes(&header)[..4], b"BRNY");
+        assert_eq!(header.layout_hash(), RecordV1::LAYOUT_HASH);
+        assert_eq!(header.value_len(), 8);
+        assert_eq!(header.check(), Ok(()));
+    }
+
+    #[test]
+    fn roundtrip_and_mismatch() {
+        let record = RecordV1 { id: 7, score: 90 };
+        let mut buf = [0u8; 29];
+        assert_eq!(write_versioned(&record, &mut buf[1..]), Ok(28));
+        assert_eq!(from_bytes_versioned::<RecordV1>(&buf[1..]), Ok(record));
+
+        assert_ne!(RecordV1::LAYOUT_HASH, RecordV2::LAYOUT_HASH);
+        assert_eq!(
+            from_bytes_versioned::<RecordV2>(&buf[1..]).err(),
+            Some(BrinyError::VERSION_MISMATCH)
+        );
+        assert_eq!(
+            from_bytes_versioned::<RecordV1>(&buf[1..28]),
+            Err(BrinyError::SIZE_BOUND_FAILURE)
+        );
+        assert_eq!(
+            write_versioned(&record, &mut buf[2..]),
+            Err(BrinyError::SIZE_BOUND_FAILURE)
+        );
+
+        buf[1] = b'X';
+        assert_eq!(
+            from_bytes_versioned::<RecordV1>(&buf[1..]),
+            Err(BrinyError::BAD_BUFFER)
+        );
+    }
+}
@@ -10,8 +10,25 @@
 #![allow(clippy::inline_always)]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+// lets derived impls refer to `::briny` from inside this crate
+extern crate self as briny;
+
+pub mod align;
+pub mod bitfield;
+pub mod ct;
+pub mod endian;
+pub mod hash;
+pub mod io;
+pub mod layout;
 pub mod raw;
 pub mod ub;
+pub mod volatile;
+pub mod zeroize;
 
 pub mod traits;
 
@@ -27,10 +44,20 @@ pub mod traits;
 /// 
 /// To find out what specifically happened, match the code with each constant
 /// descriptor.
+///
+/// # Code space
+///
+/// The code is a set of 32 flags. Bits `0..16` are reserved for the built-in
+/// errors (the original codes keep their values), while bits `16..32` are
+/// free for user-defined errors created with [`BrinyError::user`], so custom
+/// validators can report through the same type.
+///
+/// Since the code was widened from `u8` to `u32`, the error is 4-byte aligned
+/// and no longer implements [`Unaligned`](crate::traits::Unaligned).
 #[repr(transparent)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct BrinyError {
-    code: u8,
+    code: u32,
 }
 
 impl core::ops::BitOr<Self> for BrinyError {
@@ -41,16 +68,60 @@ impl core::ops::BitOr<Self> for BrinyError {
     }
 }
 
+impl core::ops::BitOrAssign<Self> for BrinyError {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.add(rhs);
+    }
+}
+
+impl core::ops::BitAnd<Self> for BrinyError {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        Self::new(self.code & rhs.code)
+    }
+}
+
+impl core::ops::BitAndAssign<Self> for BrinyError {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = *self & rhs;
+    }
+}
+
 impl BrinyError {
-    const RESERVED_CODE: u8 = 0b00000000;
+    const RESERVED_CODE: u32 = 0;
+
+    const INVALID_BITPATTERN_CODE: u32 = 1 << 0;
+
+    const SIZE_BOUND_FAILURE_CODE: u32 = 1 << 1;
+
+    const UNALIGNED_ACCESS_CODE: u32 = 1 << 2;
+
+    const BAD_BUFFER_CODE: u32 = 1 << 3;
+
+    const OUT_OF_BOUNDS_CODE: u32 = 1 << 4;
+
+    const NULL_POINTER_CODE: u32 = 1 << 5;
 
-    const INVALID_BITPATTERN_CODE: u8 = 0b00000001;
+    const CHECKSUM_MISMATCH_CODE: u32 = 1 << 6;
 
-    const SIZE_BOUND_FAILURE_CODE: u8 = 0b00000010;
+    const INTEGER_OVERFLOW_CODE: u32 = 1 << 7;
 
-    const UNALIGNED_ACCESS_CODE: u8 = 0b00000100;
+    const INVALID_UTF8_CODE: u32 = 1 << 8;
 
-    const BAD_BUFFER_CODE: u8 = 0b00001000;
+    const VERSION_MISMATCH_CODE: u32 = 1 << 9;
+
+    /// Every bit that belongs to a built-in error.
+    const BUILTIN_MASK: u32 = (1 << 10) - 1;
+
+    /// The first bit available for user-defined errors.
+    const USER_SHIFT: u32 = 16;
+
+    /// Every bit available for user-defined errors.
+    const USER_MASK: u32 = !((1 << Self::USER_SHIFT) - 1);
+
+    /// The number of distinct user-defined errors.
+    pub const USER_CODES: u32 = 32 - Self::USER_SHIFT;
 
     /// A reserved code `0` that does not work as a regular error.
     pub const RESERVED: Self = Self::new(Self::RESERVED_CODE);
@@ -67,14 +138,142 @@ impl BrinyError {
     /// An error indicating that a provided buffer is incorrect for it's use case.
     pub const BAD_BUFFER: Self = Self::new(Self::BAD_BUFFER_CODE);
 
+    /// An error indicating that an index or offset is past the end of its container.
+    pub const OUT_OF_BOUNDS: Self = Self::new(Self::OUT_OF_BOUNDS_CODE);
+
+    /// An error indicating that a pointer was unexpectedly null.
+    pub const NULL_POINTER: Self = Self::new(Self::NULL_POINTER_CODE);
+
+    /// An error indicating that stored data does not match its checksum.
+    pub const CHECKSUM_MISMATCH: Self = Self::new(Self::CHECKSUM_MISMATCH_CODE);
+
+    /// An error indicating that an integer computation overflowed.
+    pub const INTEGER_OVERFLOW: Self = Self::new(Self::INTEGER_OVERFLOW_CODE);
+
+    /// An error indicating that bytes are not valid UTF-8.
+    pub const INVALID_UTF8: Self = Self::new(Self::INVALID_UTF8_CODE);
+
+    /// An error indicating that stored data was written by an incompatible version.
+    pub const VERSION_MISMATCH: Self = Self::new(Self::VERSION_MISMATCH_CODE);
+
+    /// Every built-in flag with its name, in ascending bit order.
+    const NAMED_FLAGS: [(u32, &'static str); 10] = [
+        (Self::INVALID_BITPATTERN_CODE, "invalid bitpattern"),
+        (Self::SIZE_BOUND_FAILURE_CODE, "size bound failure"),
+        (Self::UNALIGNED_ACCESS_CODE, "unaligned access"),
+        (Self::BAD_BUFFER_CODE, "bad buffer"),
+        (Self::OUT_OF_BOUNDS_CODE, "out of bounds"),
+        (Self::NULL_POINTER_CODE, "null pointer"),
+        (Self::CHECKSUM_MISMATCH_CODE, "checksum mismatch"),
+        (Self::INTEGER_OVERFLOW_CODE, "integer overflow"),
+        (Self::INVALID_UTF8_CODE, "invalid utf-8"),
+        (Self::VERSION_MISMATCH_CODE, "version mismatch"),
+    ];
+
     /// Constructs a new error from a code.
     #[inline]
-    const fn new(code: u8) -> Self {
+    const fn new(code: u32) -> Self {
         Self {
             code
         }
     }
 
+    /// Constructs the user-defined error number `index`.
+    ///
+    /// User-defined errors never collide with the built-in ones and combine
+    /// with them like any other flag.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below [`Self::USER_CODES`].
+    #[inline]
+    #[must_use]
+    pub const fn user(index: u32) -> Self {
+        assert!(index < Self::USER_CODES, "user-defined error index out of range");
+        Self::new(1 << (Self::USER_SHIFT + index))
+    }
+
+    /// Returns the index of a single user-defined error, as passed to [`Self::user`].
+    ///
+    /// Returns `None` unless exactly one user-defined flag and no built-in flag is set.
+    #[inline]
+    #[must_use]
+    pub const fn user_index(self) -> Option<u32> {
+        if self.code & Self::USER_MASK != 0 && self.code.is_power_of_two() {
+            Some(self.code.trailing_zeros() - Self::USER_SHIFT)
+        } else {
+            None
+        }
+    }
+
+    /// Checks if the error includes any user-defined code.
+    #[inline]
+    #[must_use]
+    pub const fn is_user_defined(self) -> bool {
+        (self.code & Self::USER_MASK) != 0
+    }
+
+    /// Returns the raw code, e.g. for passing across FFI.
+    #[inline]
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        self.code
+    }
+
+    /// Reconstructs an error from a raw code.
+    ///
+    /// Returns `None` if any bit falls in the reserved, not yet assigned part of
+    /// the built-in range.
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Option<Self> {
+        if bits & !(Self::BUILTIN_MASK | Self::USER_MASK) == 0 {
+            Some(Self::new(bits))
+        } else {
+            None
+        }
+    }
+
+    /// Checks if every flag of `other` is also set in `self`.
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, other: Self) -> bool {
+        (self.code & other.code) == other.code
+    }
+
+    /// Clears every flag of `rhs` from the error.
+    #[inline]
+    #[must_use]
+    pub const fn remove(self, rhs: Self) -> Self {
+        Self::new(self.code & !rhs.code)
+    }
+
+    /// The human-readable name of a single flag.
+    ///
+    /// Returns `None` unless exactly one built-in flag is set; user-defined
+    /// errors have no name.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> Option<&'static str> {
+        let mut i = 0;
+        while i < Self::NAMED_FLAGS.len() {
+            let (code, name) = Self::NAMED_FLAGS[i];
+            if self.code == code {
+                return Some(name);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Iterates over each individual flag set in the error, built-in flags first.
+    #[inline]
+    pub fn iter(self) -> impl Iterator<Item = Self> {
+        (0..u32::BITS)
+            .map(|bit| Self::new(1 << bit))
+            .filter(move |&flag| self.contains(flag))
+    }
+
     /// Adds the two errors into a combination of multiple error codes.
     #[inline]
     #[must_use]
@@ -88,43 +287,284 @@ impl BrinyError {
     ///
     /// This returns false if and only if `self` IS [`Self::RESERVED`].
     #[inline]
+    #[must_use]
     pub const fn is_err(self) -> bool {
         self.code != 0
     }
 
     /// Checks if the error includes an unaligned access code.
     #[inline]
+    #[must_use]
     pub const fn is_unaligned_access(self) -> bool {
         (self.code & Self::UNALIGNED_ACCESS_CODE) != 0
     }
 
     /// Checks if the error includes an bad buffer code.
     #[inline]
+    #[must_use]
     pub const fn is_bad_buffer(self) -> bool {
         (self.code & Self::BAD_BUFFER_CODE) != 0
     }
 
     /// Checks if the error includes an invalid bitpattern code.
     #[inline]
+    #[must_use]
     pub const fn is_invalid_bitpattern(self) -> bool {
         (self.code & Self::INVALID_BITPATTERN_CODE) != 0
     }
 
     /// Checks if the error includes an size bound failure code.
     #[inline]
+    #[must_use]
     pub const fn is_size_bound_failure(self) -> bool {
         (self.code & Self::SIZE_BOUND_FAILURE_CODE) != 0
     }
+
+    /// Checks if the error includes an out of bounds code.
+    #[inline]
+    #[must_use]
+    pub const fn is_out_of_bounds(self) -> bool {
+        (self.code & Self::OUT_OF_BOUNDS_CODE) != 0
+    }
+
+    /// Checks if the error includes a null pointer code.
+    #[inline]
+    #[must_use]
+    pub const fn is_null_pointer(self) -> bool {
+        (self.code & Self::NULL_POINTER_CODE) != 0
+    }
+
+    /// Checks if the error includes a checksum mismatch code.
+    #[inline]
+    #[must_use]
+    pub const fn is_checksum_mismatch(self) -> bool {
+        (self.code & Self::CHECKSUM_MISMATCH_CODE) != 0
+    }
+
+    /// Checks if the error includes an integer overflow code.
+    #[inline]
+    #[must_use]
+    pub const fn is_integer_overflow(self) -> bool {
+        (self.code & Self::INTEGER_OVERFLOW_CODE) != 0
+    }
+
+    /// Checks if the error includes an invalid UTF-8 code.
+    #[inline]
+    #[must_use]
+    pub const fn is_invalid_utf8(self) -> bool {
+        (self.code & Self::INVALID_UTF8_CODE) != 0
+    }
+
+    /// Checks if the error includes a version mismatch code.
+    #[inline]
+    #[must_use]
+    pub const fn is_version_mismatch(self) -> bool {
+        (self.code & Self::VERSION_MISMATCH_CODE) != 0
+    }
 }
 
 impl core::fmt::Display for BrinyError {
+    /// Lists every set flag by name, e.g. `size bound failure | unaligned access`.
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
-        write!(f, "BrinyError:code={}", self.code)
+        if !self.is_err() {
+            return f.write_str("reserved");
+        }
+        for (i, flag) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" | ")?;
+            }
+            match (flag.name(), flag.user_index()) {
+                (Some(name), _) => f.write_str(name)?,
+                (None, Some(index)) => write!(f, "user-defined error {index}")?,
+                (None, None) => f.write_str("unknown")?,
+            }
+        }
+        Ok(())
     }
 }
 impl core::error::Error for BrinyError {}
 
+/// A [`BrinyError`] together with the sizes and offsets that caused it.
+///
+/// The compact [`BrinyError`] only records *which* checks failed. This also
+/// records the numbers involved, for diagnosing failures after the fact, and
+/// converts back into a [`BrinyError`] with [`From`].
+///
+/// Fields that do not apply to a failure (e.g. alignment for a pure size
+/// mismatch) still hold the values that were checked.
+#[derive(Debug, Clone, Copy)]
+pub struct BrinyErrorDetail {
+    error: BrinyError,
+    expected_len: usize,
+    actual_len: usize,
+    required_align: usize,
+    address_offset: usize,
+    position: usize,
+}
+
+impl BrinyErrorDetail {
+    /// Creates a detail for `error` with every other field zeroed.
+    #[inline]
+    #[must_use]
+    pub const fn new(error: BrinyError) -> Self {
+        Self {
+            error,
+            expected_len: 0,
+            actual_len: 0,
+            required_align: 0,
+            address_offset: 0,
+            position: 0,
+        }
+    }
+
+    /// Records the number of bytes that were expected and provided.
+    #[inline]
+    #[must_use]
+    pub const fn with_len(mut self, expected: usize, actual: usize) -> Self {
+        self.expected_len = expected;
+        self.actual_len = actual;
+        self
+    }
+
+    /// Records the alignment that was required and how far the address was from it.
+    #[inline]
+    #[must_use]
+    pub const fn with_align(mut self, required: usize, offset: usize) -> Self {
+        self.required_align = required;
+        self.address_offset = offset;
+        self
+    }
+
+    /// Records the byte position within the input at which the failure occurred.
+    #[inline]
+    #[must_use]
+    pub const fn with_position(mut self, position: usize) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// The compact error code.
+    #[inline]
+    #[must_use]
+    pub const fn error(self) -> BrinyError {
+        self.error
+    }
+
+    /// The number of bytes the operation expected.
+    #[inline]
+    #[must_use]
+    pub const fn expected_len(self) -> usize {
+        self.expected_len
+    }
+
+    /// The number of bytes the operation was given.
+    #[inline]
+    #[must_use]
+    pub const fn actual_len(self) -> usize {
+        self.actual_len
+    }
+
+    /// The alignment the operation required.
+    #[inline]
+    #[must_use]
+    pub const fn required_align(self) -> usize {
+        self.required_align
+    }
+
+    /// The address modulo [`Self::required_align`], which is zero when aligned.
+    #[inline]
+    #[must_use]
+    pub const fn address_offset(self) -> usize {
+        self.address_offset
+    }
+
+    /// The byte position within the input at which the failure occurred.
+    #[inline]
+    #[must_use]
+    pub const fn position(self) -> usize {
+        self.position
+    }
+}
+
+impl From<BrinyErrorDetail> for BrinyError {
+    #[inline]
+    fn from(detail: BrinyErrorDetail) -> Self {
+        detail.error
+    }
+}
+
+impl From<BrinyError> for BrinyErrorDetail {
+    #[inline]
+    fn from(error: BrinyError) -> Self {
+        Self::new(error)
+    }
+}
+
+impl core::fmt::Display for BrinyErrorDetail {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
+        write!(
+            f,
+            "{} (expected {} bytes, got {}; align {}, offset {}; at byte {})",
+            self.error,
+            self.expected_len,
+            self.actual_len,
+            self.required_align,
+            self.address_offset,
+            self.position,
+        )
+    }
+}
+impl core::error::Error for BrinyErrorDetail {}
+
 unsafe impl crate::traits::RawConvert for BrinyError {}
 unsafe impl crate::traits::StableLayout for BrinyError {}
 unsafe impl crate::traits::InteriorImmutable for BrinyError {}
-unsafe impl crate::traits::Unaligned for BrinyError {}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn flags_iterate_and_display() {
+        let err = BrinyError::SIZE_BOUND_FAILURE | BrinyError::UNALIGNED_ACCESS;
+        let mut flags = err.iter();
+        assert_eq!(flags.next(), Some(BrinyError::SIZE_BOUND_FAILURE));
+        assert_eq!(flags.next(), Some(BrinyError::UNALIGNED_ACCESS));
+        assert_eq!(flags.next(), None);
+
+        assert_eq!(err.to_string(), "size bound failure | unaligned access");
+        assert_eq!(BrinyError::RESERVED.to_string(), "reserved");
+    }
+
+    #[test]
+    fn flag_set_operations() {
+        let mut err = BrinyError::BAD_BUFFER;
+        err |= BrinyError::INVALID_BITPATTERN;
+        assert!(err.contains(BrinyError::BAD_BUFFER));
+        assert_eq!(err & BrinyError::INVALID_BITPATTERN, BrinyError::INVALID_BITPATTERN);
+        assert_eq!(err.remove(BrinyError::BAD_BUFFER).name(), Some("invalid bitpattern"));
+        assert_eq!(err.name(), None);
+
+        assert_eq!(BrinyError::from_bits(err.bits()), Some(err));
+        assert_eq!(BrinyError::from_bits(1 << 12), None);
+    }
+
+    #[test]
+    fn user_defined_codes() {
+        const CRC: BrinyError = BrinyError::user(0);
+        const LIMIT: BrinyError = BrinyError::user(BrinyError::USER_CODES - 1);
+
+        let err = CRC | BrinyError::CHECKSUM_MISMATCH;
+        assert!(err.is_user_defined() && err.is_checksum_mismatch());
+        assert_eq!(CRC.user_index(), Some(0));
+        assert_eq!(LIMIT.user_index(), Some(15));
+        assert_eq!(err.user_index(), None);
+        assert_eq!(BrinyError::from_bits(LIMIT.bits()), Some(LIMIT));
+        assert_eq!(err.to_string(), "checksum mismatch | user-defined error 0");
+        assert_eq!(BrinyError::INVALID_BITPATTERN.bits(), 1);
+        assert_eq!(BrinyError::BAD_BUFFER.bits(), 8);
+    }
+}
@@ -1,6 +1,34 @@
-use crate::{traits::Pod, BrinyError};
+use crate::{
+    traits::{InteriorImmutable, Pod},
+    BrinyError, BrinyErrorDetail,
+};
 use core::{mem, ptr, slice};
 
+/// Checks that `len` bytes at `addr` hold `expected` bytes (exactly, or at
+/// least if `exact` is false) aligned to `align`.
+#[inline(always)]
+pub const fn check_bytes(
+    addr: usize,
+    len: usize,
+    expected: usize,
+    exact: bool,
+    align: usize,
+) -> Result<(), BrinyErrorDetail> {
+    let mut err = BrinyError::RESERVED;
+    if (exact && len != expected) || len < expected {
+        err = err.add(BrinyError::SIZE_BOUND_FAILURE);
+    }
+    if addr % align != 0 {
+        err = err.add(BrinyError::UNALIGNED_ACCESS);
+    }
+    if err.is_err() {
+        return Err(BrinyErrorDetail::new(err)
+            .with_len(expected, len)
+            .with_align(align, addr % align));
+    }
+    Ok(())
+}
+
 #[inline(always)]
 pub fn slice_to_bytes<T: Pod>(slice: &[T]) -> &[u8] {
     const {
@@ -41,8 +69,27 @@ pub const fn to_bytes_mut<T: Pod>(input: &mut T) -> &mut [u8] {
     unsafe { slice::from_raw_parts_mut(ptr::from_mut::<T>(input).cast::<u8>(), size_of::<T>()) }
 }
 
+/// Reinterprets a byte slice as a slice of [`Pod`] values without copying.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::UNALIGNED_ACCESS`] if the bytes are not aligned for `T`,
+/// and [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not a multiple of
+/// `size_of::<T>()`.
 #[inline(always)]
-pub fn slice_from_bytes<T: Pod>(bytes: &[u8]) -> Result<&[T], BrinyError> {
+pub fn slice_from_bytes<T: Pod + InteriorImmutable>(bytes: &[u8]) -> Result<&[T], BrinyError> {
+    slice_from_bytes_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`slice_from_bytes`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`slice_from_bytes`].
+#[inline(always)]
+pub fn slice_from_bytes_detailed<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<&[T], BrinyErrorDetail> {
     const {
         assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
     }
@@ -52,7 +99,7 @@ pub fn slice_from_bytes<T: Pod>(bytes: &[u8]) -> Result<&[T], BrinyError> {
     let elem_size = size_of::<T>();
 
     if bytes.len() % elem_size != 0 {
-        err = err.add(BrinyError::UNALIGNED_ACCESS);
+        err = err.add(BrinyError::SIZE_BOUND_FAILURE);
     }
 
     let ptr = bytes.as_ptr();
@@ -61,7 +108,9 @@ pub fn slice_from_bytes<T: Pod>(bytes: &[u8]) -> Result<&[T], BrinyError> {
     }
 
     if err.is_err() {
-        return Err(err);
+        return Err(BrinyErrorDetail::new(err)
+            .with_len(bytes.len() - bytes.len() % elem_size, bytes.len())
+            .with_align(align_of::<T>(), (ptr as usize) % align_of::<T>()));
     }
 
     let len = bytes.len() / elem_size;
@@ -70,22 +119,30 @@ pub fn slice_from_bytes<T: Pod>(bytes: &[u8]) -> Result<&[T], BrinyError> {
     Ok(unsafe { slice::from_raw_parts(t_ptr, len) })
 }
 
+/// Copies a [`Pod`] value out of an aligned byte slice.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not exactly
+/// `size_of::<T>()`, and [`BrinyError::UNALIGNED_ACCESS`] if the bytes are not
+/// aligned for `T`.
 #[inline(always)]
 pub fn from_bytes<T: Pod>(bytes: &[u8]) -> Result<T, BrinyError> {
+    from_bytes_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`from_bytes`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`from_bytes`].
+#[inline(always)]
+pub fn from_bytes_detailed<T: Pod>(bytes: &[u8]) -> Result<T, BrinyErrorDetail> {
     const {
         assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
     }
 
-    let mut err = BrinyError::RESERVED;
-    if bytes.len() != size_of::<T>() {
-        err = err.add(BrinyError::SIZE_BOUND_FAILURE);
-    }
-    if (bytes.as_ptr() as usize) % align_of::<T>() != 0 {
-        err = err.add(BrinyError::UNALIGNED_ACCESS);
-    }
-    if err.is_err() {
-        return Err(err);
-    }
+    check_bytes(bytes.as_ptr() as usize, bytes.len(), size_of::<T>(), true, align_of::<T>())?;
 
     let mut tmp = mem::MaybeUninit::<T>::uninit();
     unsafe {
@@ -98,6 +155,304 @@ pub fn from_bytes<T: Pod>(bytes: &[u8]) -> Result<T, BrinyError> {
     }
 }
 
+/// Reinterprets an aligned byte slice as a reference to a [`Pod`] value without copying.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not exactly
+/// `size_of::<T>()`, and [`BrinyError::UNALIGNED_ACCESS`] if the bytes are not
+/// aligned for `T`.
+#[inline(always)]
+pub fn ref_from_bytes<T: Pod + InteriorImmutable>(bytes: &[u8]) -> Result<&T, BrinyError> {
+    ref_from_bytes_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`ref_from_bytes`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`ref_from_bytes`].
+#[inline(always)]
+pub fn ref_from_bytes_detailed<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<&T, BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    check_bytes(bytes.as_ptr() as usize, bytes.len(), size_of::<T>(), true, align_of::<T>())?;
+
+    Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+}
+
+/// Reinterprets an aligned mutable byte slice as a mutable reference to a [`Pod`]
+/// value, allowing it to be edited in place.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not exactly
+/// `size_of::<T>()`, and [`BrinyError::UNALIGNED_ACCESS`] if the bytes are not
+/// aligned for `T`.
+#[inline(always)]
+pub fn mut_from_bytes<T: Pod>(bytes: &mut [u8]) -> Result<&mut T, BrinyError> {
+    mut_from_bytes_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`mut_from_bytes`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`mut_from_bytes`].
+#[inline(always)]
+pub fn mut_from_bytes_detailed<T: Pod>(bytes: &mut [u8]) -> Result<&mut T, BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    check_bytes(bytes.as_ptr() as usize, bytes.len(), size_of::<T>(), true, align_of::<T>())?;
+
+    Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<T>() })
+}
+
+/// Reinterprets a byte array as a reference to a [`Pod`] value without copying.
+///
+/// The size is checked at compile time, so only the alignment can fail.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::UNALIGNED_ACCESS`] if the array is not aligned for `T`.
+#[inline(always)]
+pub fn ref_from_array<T: Pod + InteriorImmutable, const N: usize>(
+    bytes: &[u8; N],
+) -> Result<&T, BrinyError> {
+    ref_from_array_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`ref_from_array`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`ref_from_array`].
+#[inline(always)]
+pub fn ref_from_array_detailed<T: Pod + InteriorImmutable, const N: usize>(
+    bytes: &[u8; N],
+) -> Result<&T, BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+        assert!(size_of::<T>() == N, "cannot cast between types of different sizes");
+    }
+
+    check_bytes(bytes.as_ptr() as usize, N, N, true, align_of::<T>())?;
+
+    Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+}
+
+/// Reinterprets a mutable byte array as a mutable reference to a [`Pod`] value.
+///
+/// The size is checked at compile time, so only the alignment can fail.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::UNALIGNED_ACCESS`] if the array is not aligned for `T`.
+#[inline(always)]
+pub fn mut_from_array<T: Pod, const N: usize>(bytes: &mut [u8; N]) -> Result<&mut T, BrinyError> {
+    mut_from_array_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`mut_from_array`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`mut_from_array`].
+#[inline(always)]
+pub fn mut_from_array_detailed<T: Pod, const N: usize>(
+    bytes: &mut [u8; N],
+) -> Result<&mut T, BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+        assert!(size_of::<T>() == N, "cannot cast between types of different sizes");
+    }
+
+    check_bytes(bytes.as_ptr() as usize, N, N, true, align_of::<T>())?;
+
+    Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<T>() })
+}
+
+/// Reinterprets the start of an aligned byte slice as a reference to a [`Pod`]
+/// value, returning it along with the remaining bytes.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if there are fewer than
+/// `size_of::<T>()` bytes, and [`BrinyError::UNALIGNED_ACCESS`] if the bytes are
+/// not aligned for `T`.
+#[inline(always)]
+pub fn ref_from_prefix<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<(&T, &[u8]), BrinyError> {
+    ref_from_prefix_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`ref_from_prefix`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`ref_from_prefix`].
+#[inline(always)]
+pub fn ref_from_prefix_detailed<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<(&T, &[u8]), BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    check_bytes(bytes.as_ptr() as usize, bytes.len(), size_of::<T>(), false, align_of::<T>())?;
+
+    let (head, rest) = bytes.split_at(size_of::<T>());
+    Ok((unsafe { &*head.as_ptr().cast::<T>() }, rest))
+}
+
+/// Reinterprets the start of an aligned mutable byte slice as a mutable reference
+/// to a [`Pod`] value, returning it along with the remaining bytes.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if there are fewer than
+/// `size_of::<T>()` bytes, and [`BrinyError::UNALIGNED_ACCESS`] if the bytes are
+/// not aligned for `T`.
+#[inline(always)]
+pub fn mut_from_prefix<T: Pod>(bytes: &mut [u8]) -> Result<(&mut T, &mut [u8]), BrinyError> {
+    mut_from_prefix_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`mut_from_prefix`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`mut_from_prefix`].
+#[inline(always)]
+pub fn mut_from_prefix_detailed<T: Pod>(
+    bytes: &mut [u8],
+) -> Result<(&mut T, &mut [u8]), BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    check_bytes(bytes.as_ptr() as usize, bytes.len(), size_of::<T>(), false, align_of::<T>())?;
+
+    let (head, rest) = bytes.split_at_mut(size_of::<T>());
+    Ok((unsafe { &mut *head.as_mut_ptr().cast::<T>() }, rest))
+}
+
+/// Copies a [`Pod`] value out of the start of an aligned byte slice, returning it
+/// along with the remaining bytes.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if there are fewer than
+/// `size_of::<T>()` bytes, and [`BrinyError::UNALIGNED_ACCESS`] if the bytes are
+/// not aligned for `T`.
+#[inline(always)]
+pub fn from_prefix<T: Pod>(bytes: &[u8]) -> Result<(T, &[u8]), BrinyError> {
+    from_prefix_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`from_prefix`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`from_prefix`].
+#[inline(always)]
+pub fn from_prefix_detailed<T: Pod>(bytes: &[u8]) -> Result<(T, &[u8]), BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    check_bytes(bytes.as_ptr() as usize, bytes.len(), size_of::<T>(), false, align_of::<T>())?;
+
+    let (head, rest) = bytes.split_at(size_of::<T>());
+    Ok((unsafe { ptr::read(head.as_ptr().cast::<T>()) }, rest))
+}
+
+/// Copies a [`Pod`] value out of the end of a byte slice, returning it along with
+/// the leading bytes.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if there are fewer than
+/// `size_of::<T>()` bytes, and [`BrinyError::UNALIGNED_ACCESS`] if the trailing
+/// bytes are not aligned for `T`.
+#[inline(always)]
+pub fn from_suffix<T: Pod>(bytes: &[u8]) -> Result<(T, &[u8]), BrinyError> {
+    from_suffix_detailed(bytes).map_err(BrinyError::from)
+}
+
+/// Like [`from_suffix`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`from_suffix`].
+#[inline(always)]
+pub fn from_suffix_detailed<T: Pod>(bytes: &[u8]) -> Result<(T, &[u8]), BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    let Some(split) = bytes.len().checked_sub(size_of::<T>()) else {
+        return Err(BrinyErrorDetail::new(BrinyError::SIZE_BOUND_FAILURE)
+            .with_len(size_of::<T>(), bytes.len())
+            .with_align(align_of::<T>(), 0));
+    };
+    let (rest, tail) = bytes.split_at(split);
+    check_bytes(tail.as_ptr() as usize, tail.len(), size_of::<T>(), true, align_of::<T>())
+        .map_err(|detail| detail.with_position(split))?;
+
+    Ok((unsafe { ptr::read(tail.as_ptr().cast::<T>()) }, rest))
+}
+
+/// Reinterprets the start of an aligned byte slice as `count` [`Pod`] values,
+/// returning them along with the remaining bytes.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if there are fewer than
+/// `count * size_of::<T>()` bytes, and [`BrinyError::UNALIGNED_ACCESS`] if the
+/// bytes are not aligned for `T`.
+#[inline(always)]
+pub fn slice_from_prefix<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+    count: usize,
+) -> Result<(&[T], &[u8]), BrinyError> {
+    slice_from_prefix_detailed(bytes, count).map_err(BrinyError::from)
+}
+
+/// Like [`slice_from_prefix`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`slice_from_prefix`].
+#[inline(always)]
+pub fn slice_from_prefix_detailed<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+    count: usize,
+) -> Result<(&[T], &[u8]), BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    let len = count.saturating_mul(size_of::<T>());
+    check_bytes(bytes.as_ptr() as usize, bytes.len(), len, false, align_of::<T>())?;
+
+    let (head, rest) = bytes.split_at(len);
+    Ok((unsafe { slice::from_raw_parts(head.as_ptr().cast::<T>(), count) }, rest))
+}
+
+/// Copies a [`Pod`] value out of a byte slice of any alignment.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not exactly
+/// `size_of::<T>()`.
 #[inline(always)]
 pub const fn from_bytes_unaligned<T: Pod>(bytes: &[u8]) -> Result<T, BrinyError> {
     const {
@@ -121,6 +476,24 @@ pub const fn from_bytes_unaligned<T: Pod>(bytes: &[u8]) -> Result<T, BrinyError>
     }
 }
 
+/// Like [`from_bytes_unaligned`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`from_bytes_unaligned`].
+#[inline(always)]
+pub const fn from_bytes_unaligned_detailed<T: Pod>(bytes: &[u8]) -> Result<T, BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    if let Err(detail) = check_bytes(0, bytes.len(), mem::size_of::<T>(), true, 1) {
+        return Err(detail);
+    }
+
+    Ok(unsafe { ptr::read_unaligned(bytes.as_ptr().cast::<T>()) })
+}
+
 #[inline(always)]
 pub const fn cast<T: Pod, U: Pod>(input: &T) -> U {
     const {
@@ -130,34 +503,136 @@ pub const fn cast<T: Pod, U: Pod>(input: &T) -> U {
     }
 
     let src_as_u = ptr::from_ref(input).cast::<U>();
-    let val = unsafe { ptr::read_unaligned(src_as_u) };
-    val
+    unsafe { ptr::read_unaligned(src_as_u) }
 }
 
+/// Reinterprets a reference to a [`Pod`] value as a reference to another [`Pod`]
+/// type of the same size, without copying.
+///
+/// Both types must be [`InteriorImmutable`], or the shared reference could be
+/// used to mutate the value:
+///
+/// ```compile_fail
+/// use core::cell::Cell;
+///
+/// let x = 5u32;
+/// briny::raw::cast_ref::<u32, Cell<u32>>(&x).set(6);
+/// ```
+///
+/// or the value could change behind the returned reference:
+///
+/// ```compile_fail
+/// use core::cell::Cell;
+///
+/// let cell = Cell::new(5u32);
+/// let x = briny::raw::cast_ref::<Cell<u32>, u32>(&cell);
+/// cell.set(6);
+/// assert_eq!(*x, 6);
+/// ```
 #[inline(always)]
-pub const fn cast_mut<T: Pod, U: Pod>(input: &mut T) -> U {
+pub const fn cast_ref<T: Pod + InteriorImmutable, U: Pod + InteriorImmutable>(input: &T) -> &U {
     const {
         assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
         assert!(size_of::<T>() == size_of::<U>(), "cannot cast between types of different sizes");
         assert!(align_of::<T>() >= align_of::<U>(), "cannot cast unaligned types");
     }
 
-    let src_as_u = ptr::from_ref(input).cast::<U>();
-    let val = unsafe { ptr::read_unaligned(src_as_u) };
-    val
+    unsafe { &*ptr::from_ref(input).cast::<U>() }
+}
+
+/// Reinterprets a mutable reference to a [`Pod`] value as a mutable reference to
+/// another [`Pod`] type of the same size, allowing it to be edited in place.
+#[inline(always)]
+pub const fn cast_mut<T: Pod, U: Pod>(input: &mut T) -> &mut U {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+        assert!(size_of::<T>() == size_of::<U>(), "cannot cast between types of different sizes");
+        assert!(align_of::<T>() >= align_of::<U>(), "cannot cast unaligned types");
+    }
+
+    unsafe { &mut *ptr::from_mut(input).cast::<U>() }
+}
+
+/// Reinterprets a reference to a [`Pod`] value as a reference to another [`Pod`]
+/// type of the same size, checking the alignment at runtime when `U` is more
+/// strictly aligned than `T`.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::UNALIGNED_ACCESS`] if `input` is not aligned for `U`.
+#[inline(always)]
+pub fn try_cast_ref<T: Pod + InteriorImmutable, U: Pod + InteriorImmutable>(
+    input: &T,
+) -> Result<&U, BrinyError> {
+    try_cast_ref_detailed(input).map_err(BrinyError::from)
+}
+
+/// Like [`try_cast_ref`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`try_cast_ref`].
+#[inline(always)]
+pub fn try_cast_ref_detailed<T: Pod + InteriorImmutable, U: Pod + InteriorImmutable>(
+    input: &T,
+) -> Result<&U, BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+        assert!(size_of::<T>() == size_of::<U>(), "cannot cast between types of different sizes");
+    }
+
+    let ptr = ptr::from_ref(input);
+    if align_of::<U>() > align_of::<T>() {
+        check_bytes(ptr as usize, size_of::<T>(), size_of::<U>(), true, align_of::<U>())?;
+    }
+
+    Ok(unsafe { &*ptr.cast::<U>() })
+}
+
+/// Reinterprets a mutable reference to a [`Pod`] value as a mutable reference to
+/// another [`Pod`] type of the same size, checking the alignment at runtime when
+/// `U` is more strictly aligned than `T`.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::UNALIGNED_ACCESS`] if `input` is not aligned for `U`.
+#[inline(always)]
+pub fn try_cast_mut<T: Pod, U: Pod>(input: &mut T) -> Result<&mut U, BrinyError> {
+    try_cast_mut_detailed(input).map_err(BrinyError::from)
+}
+
+/// Like [`try_cast_mut`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`try_cast_mut`].
+#[inline(always)]
+pub fn try_cast_mut_detailed<T: Pod, U: Pod>(input: &mut T) -> Result<&mut U, BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+        assert!(size_of::<T>() == size_of::<U>(), "cannot cast between types of different sizes");
+    }
+
+    let ptr = ptr::from_mut(input);
+    if align_of::<U>() > align_of::<T>() {
+        check_bytes(ptr as usize, size_of::<T>(), size_of::<U>(), true, align_of::<U>())?;
+    }
+
+    Ok(unsafe { &mut *ptr.cast::<U>() })
 }
 
 #[inline(always)]
-pub const fn cast_slice<T: Pod, U: Pod>(input: &[T]) -> &[U] {
+pub const fn cast_slice<T: Pod + InteriorImmutable, U: Pod + InteriorImmutable>(
+    input: &[T],
+) -> &[U] {
     const {
         assert!(size_of::<T>() > 0 && size_of::<U>() > 0, "cannot cast between ZSTs");
         assert!(align_of::<T>() >= align_of::<U>(), "cannot cast unaligned slices");
     }
 
-    let len = size_of_val(input) / size_of::<U>();
+    let len = (size_of::<T>() * input.len()) / size_of::<U>();
     let src_as_u = input.as_ptr().cast::<U>();
-    let val = unsafe { slice::from_raw_parts(src_as_u, len) };
-    val
+    unsafe { slice::from_raw_parts(src_as_u, len) }
 }
 
 #[inline(always)]
@@ -167,10 +642,101 @@ pub const fn cast_slice_mut<T: Pod, U: Pod>(input: &mut [T]) -> &mut [U] {
         assert!(align_of::<T>() >= align_of::<U>(), "cannot cast unaligned slices");
     }
 
-    let len = size_of_val(input) / size_of::<U>();
+    let len = (size_of::<T>() * input.len()) / size_of::<U>();
     let src_as_u = input.as_mut_ptr().cast::<U>();
-    let val = unsafe { slice::from_raw_parts_mut(src_as_u, len) };
-    val
+    unsafe { slice::from_raw_parts_mut(src_as_u, len) }
+}
+
+/// Checks that a slice of `size` bytes at `addr` can be viewed as a slice of `U`.
+#[inline(always)]
+pub const fn check_slice_cast<U>(addr: usize, size: usize) -> Result<(), BrinyErrorDetail> {
+    let mut err = BrinyError::RESERVED;
+    if size % size_of::<U>() != 0 {
+        err = err.add(BrinyError::SIZE_BOUND_FAILURE);
+    }
+    if addr % align_of::<U>() != 0 {
+        err = err.add(BrinyError::UNALIGNED_ACCESS);
+    }
+    if err.is_err() {
+        return Err(BrinyErrorDetail::new(err)
+            .with_len(size - size % size_of::<U>(), size)
+            .with_align(align_of::<U>(), addr % align_of::<U>()));
+    }
+    Ok(())
+}
+
+/// Reinterprets a slice of [`Pod`] values as a slice of another [`Pod`] type,
+/// checking the alignment and length at runtime instead of at compile time.
+///
+/// Unlike [`cast_slice`], this accepts any pair of types and never drops
+/// trailing bytes.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::UNALIGNED_ACCESS`] if the slice is not aligned for `U`,
+/// and [`BrinyError::SIZE_BOUND_FAILURE`] if its size in bytes is not a multiple
+/// of `size_of::<U>()`.
+#[inline(always)]
+pub fn try_cast_slice<T: Pod + InteriorImmutable, U: Pod + InteriorImmutable>(
+    input: &[T],
+) -> Result<&[U], BrinyError> {
+    try_cast_slice_detailed(input).map_err(BrinyError::from)
+}
+
+/// Like [`try_cast_slice`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`try_cast_slice`].
+#[inline(always)]
+pub fn try_cast_slice_detailed<T: Pod + InteriorImmutable, U: Pod + InteriorImmutable>(
+    input: &[T],
+) -> Result<&[U], BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0 && size_of::<U>() > 0, "cannot cast between ZSTs");
+    }
+
+    let size = mem::size_of_val(input);
+    check_slice_cast::<U>(input.as_ptr() as usize, size)?;
+
+    let src_as_u = input.as_ptr().cast::<U>();
+    Ok(unsafe { slice::from_raw_parts(src_as_u, size / size_of::<U>()) })
+}
+
+/// Reinterprets a mutable slice of [`Pod`] values as a slice of another [`Pod`]
+/// type, checking the alignment and length at runtime instead of at compile time.
+///
+/// Unlike [`cast_slice_mut`], this accepts any pair of types and never drops
+/// trailing bytes.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::UNALIGNED_ACCESS`] if the slice is not aligned for `U`,
+/// and [`BrinyError::SIZE_BOUND_FAILURE`] if its size in bytes is not a multiple
+/// of `size_of::<U>()`.
+#[inline(always)]
+pub fn try_cast_slice_mut<T: Pod, U: Pod>(input: &mut [T]) -> Result<&mut [U], BrinyError> {
+    try_cast_slice_mut_detailed(input).map_err(BrinyError::from)
+}
+
+/// Like [`try_cast_slice_mut`], but reports a [`BrinyErrorDetail`] on failure.
+///
+/// # Errors
+///
+/// See [`try_cast_slice_mut`].
+#[inline(always)]
+pub fn try_cast_slice_mut_detailed<T: Pod, U: Pod>(
+    input: &mut [T],
+) -> Result<&mut [U], BrinyErrorDetail> {
+    const {
+        assert!(size_of::<T>() > 0 && size_of::<U>() > 0, "cannot cast between ZSTs");
+    }
+
+    let size = mem::size_of_val(input);
+    check_slice_cast::<U>(input.as_ptr() as usize, size)?;
+
+    let src_as_u = input.as_mut_ptr().cast::<U>();
+    Ok(unsafe { slice::from_raw_parts_mut(src_as_u, size / size_of::<U>()) })
 }
 
 #[cfg(test)]
@@ -187,6 +753,7 @@ mod tests {
     unsafe impl crate::traits::RawConvert for ThePod {}
     unsafe impl crate::traits::StableLayout for ThePod {}
     unsafe impl crate::traits::Pod for ThePod {}
+    unsafe impl crate::traits::InteriorImmutable for ThePod {}
 
     #[test]
     fn stack_misaligned_slice_from_bytes() {
@@ -206,7 +773,7 @@ mod tests {
 
     #[test]
     fn to_bytes_roundtrip() {
-        let val = 0x12345678u32;
+        let val = 0x1234_5678u32;
         let bytes = slice_to_bytes(slice::from_ref(&val));
         let restored = from_bytes::<u32>(bytes).unwrap();
         assert_eq!(val, restored);
@@ -222,7 +789,7 @@ mod tests {
 
     #[test]
     fn cast_between_same_size_types() {
-        let original: u32 = 0xDEADBEEF;
+        let original: u32 = 0xDEAD_BEEF;
         let casted = cast::<u32, f32>(&original);
         let restored = cast::<f32, u32>(&casted);
         assert_eq!(restored, original);
@@ -232,7 +799,7 @@ mod tests {
     fn custom_struct_bytes_roundtrip() {
         let pod = ThePod {
             a: 0xABCD,
-            b: 0x12345678,
+            b: 0x1234_5678,
         };
         let bytes = slice_to_bytes(slice::from_ref(&pod));
         let restored: ThePod = from_bytes(bytes).unwrap();
@@ -245,7 +812,7 @@ mod tests {
 
         let pod = ThePod {
             a: 0x1122,
-            b: 0x33445566,
+            b: 0x3344_5566,
         };
         let raw: u64 = cast(&pod);
         let back: ThePod = cast(&raw);
@@ -268,4 +835,148 @@ mod tests {
         let result = from_bytes_unaligned::<u32>(slice).unwrap();
         assert_eq!(result, val);
     }
+
+    #[test]
+    fn mut_from_bytes_edits_in_place() {
+        let mut pod = ThePod { a: 1, b: 2 };
+        let bytes = to_bytes_mut(&mut pod);
+
+        let view = mut_from_bytes::<ThePod>(bytes).unwrap();
+        view.b = 0xFFFF;
+        assert_eq!(pod, ThePod { a: 1, b: 0xFFFF });
+
+        let arr = [7u64];
+        let bytes = slice_to_bytes(&arr);
+        assert_eq!(*ref_from_bytes::<u64>(bytes).unwrap(), 7);
+        assert!(ref_from_bytes::<u64>(&bytes[1..]).unwrap_err().is_size_bound_failure());
+    }
+
+    #[test]
+    fn ref_from_array_checks_alignment() {
+        #[repr(align(4))]
+        struct Aligned([u8; 8]);
+
+        let buf = Aligned(*b"abcdefgh");
+        let word = ref_from_array::<[u8; 4], 4>(buf.0[..4].try_into().unwrap()).unwrap();
+        assert_eq!(word, b"abcd");
+
+        let misaligned: &[u8; 4] = buf.0[1..5].try_into().unwrap();
+        assert!(ref_from_array::<u32, 4>(misaligned).unwrap_err().is_unaligned_access());
+    }
+
+    #[test]
+    fn prefix_and_suffix_split() {
+        let packet = [ThePod { a: 1, b: 2 }, ThePod { a: 3, b: 4 }, ThePod { a: 5, b: 6 }];
+        let bytes = slice_to_bytes(&packet);
+
+        let (first, rest) = from_prefix::<ThePod>(bytes).unwrap();
+        assert_eq!(first, packet[0]);
+        assert_eq!(rest.len(), 16);
+
+        let (pair, rest) = slice_from_prefix::<ThePod>(rest, 2).unwrap();
+        assert_eq!(pair, &packet[1..]);
+        assert!(rest.is_empty());
+
+        let (last, rest) = from_suffix::<ThePod>(bytes).unwrap();
+        assert_eq!(last, packet[2]);
+        assert_eq!(rest.len(), 16);
+
+        let err = slice_from_prefix::<ThePod>(bytes, 4).unwrap_err();
+        assert!(err.is_size_bound_failure());
+        assert!(slice_from_prefix::<ThePod>(bytes, usize::MAX).is_err());
+        assert!(ref_from_prefix::<ThePod>(&bytes[4..]).unwrap_err().is_unaligned_access());
+    }
+
+    #[test]
+    fn mut_from_prefix_patches_header() {
+        let mut words = [0u32; 4];
+        let bytes = slice_to_bytes_mut(&mut words);
+
+        let (len, rest) = mut_from_prefix::<u32>(bytes).unwrap();
+        *len = 12;
+        assert_eq!(rest.len(), 12);
+        assert_eq!(words[0], 12);
+    }
+
+    #[test]
+    fn try_cast_slice_checks_at_runtime() {
+        let words = [1u32, 2, 3, 4];
+        let bytes: &[u8] = try_cast_slice(&words).unwrap();
+        assert_eq!(bytes.len(), 16);
+
+        let back: &[u32] = try_cast_slice(bytes).unwrap();
+        assert_eq!(back, &words);
+
+        let err = try_cast_slice::<u8, u32>(&bytes[..6]).unwrap_err();
+        assert!(err.is_size_bound_failure() && !err.is_unaligned_access());
+
+        let err = try_cast_slice::<u8, u32>(&bytes[1..5]).unwrap_err();
+        assert!(err.is_unaligned_access() && !err.is_size_bound_failure());
+
+        let mut words = [0u64; 2];
+        let halves: &mut [u32] = try_cast_slice_mut(&mut words).unwrap();
+        halves[3] = u32::MAX;
+        assert_ne!(words[1], 0);
+    }
+
+    #[test]
+    fn cast_mut_reinterprets_in_place() {
+        let mut pod = ThePod { a: 0, b: 0 };
+        let raw: &mut u64 = cast_mut(&mut pod);
+        *raw = u64::MAX;
+        assert_eq!(pod, ThePod { a: u32::MAX, b: u32::MAX });
+
+        let halves: &[u32; 2] = cast_ref(&pod);
+        assert_eq!(halves, &[u32::MAX; 2]);
+    }
+
+    #[test]
+    fn try_cast_ref_checks_alignment() {
+        let words = [0u32; 4];
+        let (wide, narrow) = if (words.as_ptr() as usize) % align_of::<u64>() == 0 {
+            (&words[0..2], &words[1..3])
+        } else {
+            (&words[1..3], &words[0..2])
+        };
+
+        let wide: &[u32; 2] = wide.try_into().unwrap();
+        let narrow: &[u32; 2] = narrow.try_into().unwrap();
+        assert_eq!(*try_cast_ref::<[u32; 2], u64>(wide).unwrap(), 0);
+        assert!(try_cast_ref::<[u32; 2], u64>(narrow).unwrap_err().is_unaligned_access());
+
+        let mut value = 0u32;
+        *try_cast_mut::<u32, [u8; 4]>(&mut value).unwrap() = [1; 4];
+        assert_eq!(value, 0x0101_0101);
+    }
+
+    #[test]
+    fn detailed_errors_report_sizes_and_offsets() {
+        let words = [0u64; 3];
+        let bytes = slice_to_bytes(&words);
+
+        let detail = from_bytes_detailed::<u64>(&bytes[1..6]).unwrap_err();
+        assert!(detail.error().is_size_bound_failure());
+        assert!(detail.error().is_unaligned_access());
+        assert_eq!(detail.expected_len(), 8);
+        assert_eq!(detail.actual_len(), 5);
+        assert_eq!(detail.required_align(), align_of::<u64>());
+        assert_eq!(detail.address_offset(), 1);
+
+        let detail = from_suffix_detailed::<u64>(&bytes[..20]).unwrap_err();
+        assert_eq!(detail.position(), 12);
+        assert_eq!(detail.address_offset(), 4);
+
+        let detail = try_cast_slice_detailed::<u64, [u8; 5]>(&words).unwrap_err();
+        assert_eq!((detail.expected_len(), detail.actual_len()), (20, 24));
+
+        let err: BrinyError = slice_from_prefix_detailed::<u64>(bytes, 4).unwrap_err().into();
+        assert!(err.is_size_bound_failure());
+    }
+
+    #[test]
+    fn slice_length_mismatch_is_a_size_failure() {
+        let words = [0u32; 2];
+        let err = slice_from_bytes::<u32>(&slice_to_bytes(&words)[..6]).unwrap_err();
+        assert_eq!(err, BrinyError::SIZE_BOUND_FAILURE);
+    }
 }
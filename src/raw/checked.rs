@@ -0,0 +1,140 @@
+use super::cast::{check_bytes, check_slice_cast};
+use crate::{
+    traits::{CheckedBitPattern, InteriorImmutable},
+    BrinyError,
+};
+use core::{mem, ptr, slice};
+
+/// Copies a [`CheckedBitPattern`] value out of an aligned byte slice after
+/// validating it.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not exactly
+/// `size_of::<T>()`, [`BrinyError::UNALIGNED_ACCESS`] if the bytes are not
+/// aligned for `T`, and [`BrinyError::INVALID_BITPATTERN`] if the bytes are not
+/// a valid `T`.
+#[inline(always)]
+pub fn try_from_bytes<T: CheckedBitPattern>(bytes: &[u8]) -> Result<T, BrinyError> {
+    check_valid::<T>(bytes)?;
+    Ok(unsafe { ptr::read(bytes.as_ptr().cast::<T>()) })
+}
+
+/// Reinterprets an aligned byte slice as a reference to a [`CheckedBitPattern`]
+/// value after validating it.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not exactly
+/// `size_of::<T>()`, [`BrinyError::UNALIGNED_ACCESS`] if the bytes are not
+/// aligned for `T`, and [`BrinyError::INVALID_BITPATTERN`] if the bytes are not
+/// a valid `T`.
+#[inline(always)]
+pub fn try_ref_from_bytes<T: CheckedBitPattern + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<&T, BrinyError> {
+    check_valid::<T>(bytes)?;
+    Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+}
+
+/// Checks that `bytes` are a valid, aligned `T`.
+#[inline(always)]
+fn check_valid<T: CheckedBitPattern>(bytes: &[u8]) -> Result<(), BrinyError> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    let addr = bytes.as_ptr() as usize;
+    check_bytes(addr, bytes.len(), size_of::<T>(), true, align_of::<T>())?;
+
+    if !T::is_valid_bit_pattern(bytes) {
+        return Err(BrinyError::INVALID_BITPATTERN);
+    }
+    Ok(())
+}
+
+/// Reinterprets an aligned byte slice as a slice of [`CheckedBitPattern`]
+/// values after validating every element.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::SIZE_BOUND_FAILURE`] if the length is not a multiple
+/// of `size_of::<T>()`, [`BrinyError::UNALIGNED_ACCESS`] if the bytes are not
+/// aligned for `T`, and [`BrinyError::INVALID_BITPATTERN`] if any element is
+/// not a valid `T`.
+#[inline(always)]
+pub fn try_slice_from_bytes<T: CheckedBitPattern + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<&[T], BrinyError> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    check_slice_cast::<T>(bytes.as_ptr() as usize, bytes.len())?;
+
+    if !bytes
+        .chunks_exact(size_of::<T>())
+        .all(T::is_valid_bit_pattern)
+    {
+        return Err(BrinyError::INVALID_BITPATTERN);
+    }
+
+    let len = bytes.len() / mem::size_of::<T>();
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<T>(), len) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::num::NonZeroU32;
+
+    #[test]
+    fn bool_rejects_invalid_bytes() {
+        assert!(try_from_bytes::<bool>(&[1]).unwrap());
+        assert!(!try_from_bytes::<bool>(&[0]).unwrap());
+        assert!(try_from_bytes::<bool>(&[2])
+            .unwrap_err()
+            .is_invalid_bitpattern());
+    }
+
+    #[test]
+    fn non_zero_rejects_zero() {
+        #[repr(align(4))]
+        struct Aligned([u8; 4]);
+
+        let zero = 0u32.to_ne_bytes();
+        let one = 1u32.to_ne_bytes();
+
+        let err = try_from_bytes::<NonZeroU32>(&Aligned(zero).0).unwrap_err();
+        assert!(err.is_invalid_bitpattern());
+        let value = try_from_bytes::<NonZeroU32>(&Aligned(one).0).unwrap();
+        assert_eq!(value.get(), 1);
+    }
+
+    #[test]
+    fn char_rejects_surrogates() {
+        #[repr(align(4))]
+        struct Aligned([u8; 8]);
+
+        let mut buf = Aligned([0; 8]);
+        buf.0[..4].copy_from_slice(&u32::from('x').to_ne_bytes());
+        buf.0[4..].copy_from_slice(&0xD800u32.to_ne_bytes());
+
+        assert_eq!(*try_ref_from_bytes::<char>(&buf.0[..4]).unwrap(), 'x');
+        assert!(try_slice_from_bytes::<char>(&buf.0)
+            .unwrap_err()
+            .is_invalid_bitpattern());
+    }
+
+    #[test]
+    fn slice_length_mismatch() {
+        #[repr(align(2))]
+        struct Aligned([u8; 3]);
+
+        let bytes = [0u8, 1, 0];
+        assert_eq!(try_slice_from_bytes::<bool>(&bytes).unwrap(), &[false, true, false]);
+
+        let err = try_slice_from_bytes::<u16>(&Aligned([0; 3]).0).unwrap_err();
+        assert!(err.is_size_bound_failure());
+    }
+}
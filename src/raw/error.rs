@@ -0,0 +1,210 @@
+use crate::BrinyError;
+use core::{any::type_name, fmt, marker::PhantomData};
+
+macro_rules! cast_error {
+    ($(#[$meta:meta])* $name:ident, $error:expr) => {
+        $(#[$meta])*
+        pub struct $name<Src, Dst: ?Sized> {
+            src: Src,
+            dst: PhantomData<fn(*const Dst)>,
+        }
+
+        impl<Src, Dst: ?Sized> $name<Src, Dst> {
+            /// Wraps the input of a failed cast.
+            #[inline]
+            pub const fn new(src: Src) -> Self {
+                Self {
+                    src,
+                    dst: PhantomData,
+                }
+            }
+
+            /// Borrows the input of the failed cast.
+            #[inline]
+            pub const fn src(&self) -> &Src {
+                &self.src
+            }
+
+            /// Hands back the input of the failed cast.
+            #[inline]
+            pub fn into_src(self) -> Src {
+                self.src
+            }
+
+            /// Transforms the carried input, keeping the kind of failure.
+            #[inline]
+            pub fn map_src<NewSrc>(self, f: impl FnOnce(Src) -> NewSrc) -> $name<NewSrc, Dst> {
+                $name::new(f(self.src))
+            }
+        }
+
+        impl<Src: Clone, Dst: ?Sized> Clone for $name<Src, Dst> {
+            fn clone(&self) -> Self {
+                Self::new(self.src.clone())
+            }
+        }
+
+        impl<Src: Copy, Dst: ?Sized> Copy for $name<Src, Dst> {}
+
+        impl<Src: PartialEq, Dst: ?Sized> PartialEq for $name<Src, Dst> {
+            fn eq(&self, other: &Self) -> bool {
+                self.src == other.src
+            }
+        }
+
+        impl<Src: Eq, Dst: ?Sized> Eq for $name<Src, Dst> {}
+
+        impl<Src: fmt::Debug, Dst: ?Sized> fmt::Debug for $name<Src, Dst> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("src", &self.src)
+                    .field("dst", &type_name::<Dst>())
+                    .finish()
+            }
+        }
+
+        impl<Src, Dst: ?Sized> fmt::Display for $name<Src, Dst> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} casting `{}` into `{}`", $error, type_name::<Src>(), type_name::<Dst>())
+            }
+        }
+
+        impl<Src: fmt::Debug, Dst: ?Sized> core::error::Error for $name<Src, Dst> {}
+
+        impl<Src, Dst: ?Sized> From<$name<Src, Dst>> for BrinyError {
+            fn from(_: $name<Src, Dst>) -> Self {
+                $error
+            }
+        }
+
+        impl<Src, Dst: ?Sized> From<$name<Src, Dst>> for CastError<Src, Dst> {
+            fn from(err: $name<Src, Dst>) -> Self {
+                Self::$name(err)
+            }
+        }
+    };
+}
+
+cast_error!(
+    /// The input of a cast into `Dst` had the wrong length.
+    SizeError,
+    BrinyError::SIZE_BOUND_FAILURE
+);
+
+cast_error!(
+    /// The input of a cast into `Dst` was not aligned for it.
+    AlignmentError,
+    BrinyError::UNALIGNED_ACCESS
+);
+
+cast_error!(
+    /// The input of a cast into `Dst` was not a valid bitpattern for it.
+    ValidityError,
+    BrinyError::INVALID_BITPATTERN
+);
+
+/// Why a cast of `Src` into `Dst` failed, carrying the original input.
+///
+/// Unlike a bare [`BrinyError`], the input is handed back with
+/// [`CastError::into_src`] so owned buffers and parser state survive a failed
+/// cast. Only the first failure is reported, in the order size, alignment,
+/// validity.
+pub enum CastError<Src, Dst: ?Sized> {
+    /// See [`SizeError`].
+    SizeError(SizeError<Src, Dst>),
+    /// See [`AlignmentError`].
+    AlignmentError(AlignmentError<Src, Dst>),
+    /// See [`ValidityError`].
+    ValidityError(ValidityError<Src, Dst>),
+}
+
+impl<Src, Dst: ?Sized> CastError<Src, Dst> {
+    /// Borrows the input of the failed cast.
+    #[inline]
+    pub const fn src(&self) -> &Src {
+        match self {
+            Self::SizeError(err) => err.src(),
+            Self::AlignmentError(err) => err.src(),
+            Self::ValidityError(err) => err.src(),
+        }
+    }
+
+    /// Hands back the input of the failed cast.
+    #[inline]
+    pub fn into_src(self) -> Src {
+        match self {
+            Self::SizeError(err) => err.into_src(),
+            Self::AlignmentError(err) => err.into_src(),
+            Self::ValidityError(err) => err.into_src(),
+        }
+    }
+
+    /// Transforms the carried input, keeping the kind of failure.
+    #[inline]
+    pub fn map_src<NewSrc>(self, f: impl FnOnce(Src) -> NewSrc) -> CastError<NewSrc, Dst> {
+        match self {
+            Self::SizeError(err) => CastError::SizeError(err.map_src(f)),
+            Self::AlignmentError(err) => CastError::AlignmentError(err.map_src(f)),
+            Self::ValidityError(err) => CastError::ValidityError(err.map_src(f)),
+        }
+    }
+
+    /// The [`BrinyError`] flag matching the kind of failure.
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> BrinyError {
+        match self {
+            Self::SizeError(_) => BrinyError::SIZE_BOUND_FAILURE,
+            Self::AlignmentError(_) => BrinyError::UNALIGNED_ACCESS,
+            Self::ValidityError(_) => BrinyError::INVALID_BITPATTERN,
+        }
+    }
+}
+
+impl<Src: Clone, Dst: ?Sized> Clone for CastError<Src, Dst> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::SizeError(err) => Self::SizeError(err.clone()),
+            Self::AlignmentError(err) => Self::AlignmentError(err.clone()),
+            Self::ValidityError(err) => Self::ValidityError(err.clone()),
+        }
+    }
+}
+
+impl<Src: Copy, Dst: ?Sized> Copy for CastError<Src, Dst> {}
+
+impl<Src: PartialEq, Dst: ?Sized> PartialEq for CastError<Src, Dst> {
+    fn eq(&self, other: &Self) -> bool {
+        self.error() == other.error() && self.src() == other.src()
+    }
+}
+
+impl<Src: Eq, Dst: ?Sized> Eq for CastError<Src, Dst> {}
+
+impl<Src: fmt::Debug, Dst: ?Sized> fmt::Debug for CastError<Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeError(err) => fmt::Debug::fmt(err, f),
+            Self::AlignmentError(err) => fmt::Debug::fmt(err, f),
+            Self::ValidityError(err) => fmt::Debug::fmt(err, f),
+        }
+    }
+}
+
+impl<Src, Dst: ?Sized> fmt::Display for CastError<Src, Dst> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SizeError(err) => fmt::Display::fmt(err, f),
+            Self::AlignmentError(err) => fmt::Display::fmt(err, f),
+            Self::ValidityError(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl<Src: fmt::Debug, Dst: ?Sized> core::error::Error for CastError<Src, Dst> {}
+
+impl<Src, Dst: ?Sized> From<CastError<Src, Dst>> for BrinyError {
+    fn from(err: CastError<Src, Dst>) -> Self {
+        err.error()
+    }
+}
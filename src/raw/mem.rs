@@ -0,0 +1,72 @@
+//! Safe wrappers over the memory operations the rest of the crate builds on,
+//! so that their unsafe code stays inside this module.
+
+use crate::traits::{Pod, Zeroable};
+use core::{
+    cell::UnsafeCell,
+    mem::{self, MaybeUninit},
+    ptr,
+};
+
+/// Returns a value with every byte set to zero.
+#[inline(always)]
+pub const fn zeroed<T: Zeroable>() -> T {
+    unsafe { mem::zeroed() }
+}
+
+/// Zeroes `slot` in place and returns it as initialized.
+#[inline(always)]
+pub fn zero_in_place<T: Zeroable>(slot: &mut MaybeUninit<T>) -> &mut T {
+    unsafe {
+        ptr::write_bytes(slot.as_mut_ptr(), 0, 1);
+        slot.assume_init_mut()
+    }
+}
+
+/// Overwrites every byte of `value` with zero without dropping the old value.
+#[inline(always)]
+pub const fn zero<T: Zeroable>(value: &mut T) {
+    unsafe { ptr::write_bytes(ptr::from_mut(value), 0, 1) }
+}
+
+/// Copies a [`Pod`] value into another [`Pod`] type of the same size,
+/// regardless of the alignment of either.
+#[inline(always)]
+pub const fn read_unaligned<T: Pod, U: Pod>(src: &T) -> U {
+    const {
+        assert!(size_of::<T>() == size_of::<U>(), "cannot copy between types of different sizes");
+    }
+
+    unsafe { ptr::read_unaligned(ptr::from_ref(src).cast::<U>()) }
+}
+
+/// Overwrites a [`Pod`] value with another of the same size, regardless of the
+/// alignment of either.
+#[inline(always)]
+pub const fn write_unaligned<T: Pod, U: Pod>(dst: &mut T, value: U) {
+    const {
+        assert!(size_of::<T>() == size_of::<U>(), "cannot copy between types of different sizes");
+    }
+
+    unsafe { ptr::write_unaligned(ptr::from_mut(dst).cast::<U>(), value) }
+}
+
+/// Reads the value in `cell` with a volatile load.
+#[inline(always)]
+pub fn read_volatile<T: Pod>(cell: &UnsafeCell<T>) -> T {
+    unsafe { ptr::read_volatile(cell.get()) }
+}
+
+/// Writes `value` into `cell` with a volatile store.
+#[inline(always)]
+pub fn write_volatile<T: Pod>(cell: &UnsafeCell<T>, value: T) {
+    unsafe { ptr::write_volatile(cell.get(), value) }
+}
+
+/// Overwrites every byte of `bytes` with zero using volatile stores.
+#[inline(always)]
+pub fn zero_volatile(bytes: &mut [u8]) {
+    for byte in bytes {
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+}
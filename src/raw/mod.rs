@@ -2,13 +2,48 @@
 //!
 //! # Safety
 //!
-//! This is the only module containing unsafe code, but it has a lot of it! The unsafe code in this module is for good reason though - It allows for casting between arbitrary types and making safe abstractions over unsafe ones.
+//! Apart from tests, this is the only module containing unsafe code, but it has a lot of it! The unsafe code in this module is for good reason though - It allows for casting between arbitrary types and making safe abstractions over unsafe ones.
 //!
-//! Traits like `Pod` provide useful methods to handle this data safely.
+//! Traits like `Pod` provide useful methods to handle this data safely, while
+//! `CheckedBitPattern` validates the bytes of types that reject some bitpatterns.
 
 mod cast;
 pub use cast::{
     cast, cast_mut, from_bytes, from_bytes_unaligned, slice_from_bytes,
     slice_to_bytes, slice_to_bytes_mut, to_bytes, to_bytes_mut,
-    cast_slice, cast_slice_mut,
+    cast_slice, cast_slice_mut, ref_from_bytes, mut_from_bytes, ref_from_array,
+    mut_from_array, from_prefix, from_suffix, ref_from_prefix, mut_from_prefix,
+    slice_from_prefix, try_cast_slice, try_cast_slice_mut, cast_ref, try_cast_ref,
+    try_cast_mut, slice_from_bytes_detailed, from_bytes_detailed, ref_from_bytes_detailed,
+    mut_from_bytes_detailed, ref_from_array_detailed, mut_from_array_detailed,
+    ref_from_prefix_detailed, mut_from_prefix_detailed, from_prefix_detailed,
+    from_suffix_detailed, slice_from_prefix_detailed, from_bytes_unaligned_detailed,
+    try_cast_ref_detailed, try_cast_mut_detailed, try_cast_slice_detailed,
+    try_cast_slice_mut_detailed,
+};
+
+mod checked;
+pub use checked::{try_from_bytes, try_ref_from_bytes, try_slice_from_bytes};
+
+mod mem;
+pub(crate) use mem::{
+    read_unaligned, read_volatile, write_unaligned, write_volatile, zero, zero_in_place,
+    zero_volatile, zeroed,
+};
+
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "alloc")]
+pub use owned::{
+    box_from_bytes, bytes_of_vec, cast_box, cast_vec, try_cast_vec, vec_from_bytes, zeroed_box,
+    zeroed_vec,
+};
+
+mod error;
+pub use error::{AlignmentError, CastError, SizeError, ValidityError};
+
+mod typed;
+pub use typed::{
+    mut_from_bytes_typed, ref_from_bytes_typed, ref_from_prefix_typed, slice_from_bytes_typed,
+    try_ref_from_bytes_typed,
 };
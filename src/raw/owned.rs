@@ -0,0 +1,256 @@
+use super::{AlignmentError, CastError, SizeError};
+use crate::{
+    traits::{Pod, Zeroable},
+    BrinyError,
+};
+use alloc::{
+    alloc::{alloc_zeroed, handle_alloc_error, Layout},
+    boxed::Box,
+    vec::Vec,
+};
+use core::{mem::ManuallyDrop, ptr};
+
+/// Reinterprets a [`Vec`] of one [`Pod`] type as another without reallocating.
+///
+/// Both types must have the same size and alignment, which is checked at
+/// compile time.
+#[inline(always)]
+#[must_use]
+pub fn cast_vec<T: Pod, U: Pod>(vec: Vec<T>) -> Vec<U> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+        assert!(
+            size_of::<T>() == size_of::<U>(),
+            "cannot cast between differently sized types"
+        );
+        assert!(
+            align_of::<T>() == align_of::<U>(),
+            "cannot cast between differently aligned allocations"
+        );
+    }
+
+    let mut vec = ManuallyDrop::new(vec);
+    unsafe { Vec::from_raw_parts(vec.as_mut_ptr().cast::<U>(), vec.len(), vec.capacity()) }
+}
+
+/// Reinterprets a [`Vec`] of one [`Pod`] type as another without reallocating,
+/// adjusting its length and capacity to the new element size.
+///
+/// # Errors
+///
+/// The allocation is never copied to make a cast fit; the [`Vec`] is handed
+/// back inside a [`CastError`] instead. Returns a [`SizeError`] if its length
+/// or capacity in bytes is not a multiple of `size_of::<U>()`, or an
+/// [`AlignmentError`] if the alignments of `T` and `U` differ.
+#[inline(always)]
+pub fn try_cast_vec<T: Pod, U: Pod>(vec: Vec<T>) -> Result<Vec<U>, CastError<Vec<T>, Vec<U>>> {
+    const {
+        assert!(
+            size_of::<T>() > 0 && size_of::<U>() > 0,
+            "cannot cast between ZSTs"
+        );
+    }
+
+    let len = vec.len() * size_of::<T>();
+    let capacity = vec.capacity() * size_of::<T>();
+
+    if len % size_of::<U>() != 0 || capacity % size_of::<U>() != 0 {
+        return Err(SizeError::new(vec).into());
+    }
+    if align_of::<T>() != align_of::<U>() {
+        return Err(AlignmentError::new(vec).into());
+    }
+
+    let mut vec = ManuallyDrop::new(vec);
+    let ptr = vec.as_mut_ptr().cast::<U>();
+    Ok(unsafe { Vec::from_raw_parts(ptr, len / size_of::<U>(), capacity / size_of::<U>()) })
+}
+
+/// Turns a [`Vec`] of [`Pod`] values into its raw bytes without reallocating.
+///
+/// # Errors
+///
+/// Returns an [`AlignmentError`] holding the [`Vec`] if `T` is aligned to more
+/// than one byte, because the allocation could then not be freed as bytes.
+/// Copy with [`slice_to_bytes`](super::slice_to_bytes) instead.
+#[inline(always)]
+pub fn bytes_of_vec<T: Pod>(vec: Vec<T>) -> Result<Vec<u8>, CastError<Vec<T>, Vec<u8>>> {
+    try_cast_vec(vec)
+}
+
+/// Reinterprets a [`Box`] of one [`Pod`] type as another without reallocating.
+///
+/// Both types must have the same size and alignment, which is checked at
+/// compile time.
+#[inline(always)]
+#[must_use]
+pub fn cast_box<T: Pod, U: Pod>(boxed: Box<T>) -> Box<U> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+        assert!(
+            size_of::<T>() == size_of::<U>(),
+            "cannot cast between differently sized types"
+        );
+        assert!(
+            align_of::<T>() == align_of::<U>(),
+            "cannot cast between differently aligned allocations"
+        );
+    }
+
+    unsafe { Box::from_raw(Box::into_raw(boxed).cast::<U>()) }
+}
+
+/// Copies bytes into a newly allocated [`Box`], regardless of their alignment.
+///
+/// # Errors
+///
+/// Returns a [`SizeError`] holding the bytes if their length is not exactly
+/// `size_of::<T>()`.
+#[inline(always)]
+pub fn box_from_bytes<T: Pod>(bytes: &[u8]) -> Result<Box<T>, CastError<&[u8], T>> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    if bytes.len() != size_of::<T>() {
+        return Err(SizeError::new(bytes).into());
+    }
+
+    let mut boxed = zeroed_box::<T>();
+    super::to_bytes_mut(&mut *boxed).copy_from_slice(bytes);
+    Ok(boxed)
+}
+
+/// Copies bytes into a newly allocated [`Vec`], regardless of their alignment.
+///
+/// # Errors
+///
+/// Returns a [`SizeError`] holding the bytes if their length is not a multiple
+/// of `size_of::<T>()`.
+#[inline(always)]
+pub fn vec_from_bytes<T: Pod>(bytes: &[u8]) -> Result<Vec<T>, CastError<&[u8], [T]>> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    if bytes.len() % size_of::<T>() != 0 {
+        return Err(SizeError::new(bytes).into());
+    }
+
+    let Ok(mut vec) = zeroed_vec::<T>(bytes.len() / size_of::<T>()) else {
+        return Err(SizeError::new(bytes).into());
+    };
+    super::slice_to_bytes_mut(&mut vec).copy_from_slice(bytes);
+    Ok(vec)
+}
+
+/// Allocates a zeroed `T` directly on the heap, without building it on the
+/// stack first.
+#[inline(always)]
+#[must_use]
+pub fn zeroed_box<T: Zeroable>() -> Box<T> {
+    let layout = Layout::new::<T>();
+    if layout.size() == 0 {
+        return Box::new(T::zeroed());
+    }
+
+    let ptr = unsafe { alloc_zeroed(layout) };
+    if ptr.is_null() {
+        handle_alloc_error(layout);
+    }
+    unsafe { Box::from_raw(ptr.cast::<T>()) }
+}
+
+/// Allocates `len` zeroed values of `T` directly on the heap.
+///
+/// # Errors
+///
+/// Returns [`BrinyError::INTEGER_OVERFLOW`] if `len` values of `T` do not fit
+/// in an allocation.
+#[inline(always)]
+pub fn zeroed_vec<T: Zeroable>(len: usize) -> Result<Vec<T>, BrinyError> {
+    let layout = Layout::array::<T>(len).map_err(|_| BrinyError::INTEGER_OVERFLOW)?;
+    if layout.size() == 0 {
+        let mut vec = Vec::with_capacity(len);
+        vec.resize_with(len, T::zeroed);
+        return Ok(vec);
+    }
+
+    let ptr = unsafe { alloc_zeroed(layout) };
+    if ptr.is_null() {
+        handle_alloc_error(layout);
+    }
+    let slice = ptr::slice_from_raw_parts_mut(ptr.cast::<T>(), len);
+    Ok(unsafe { Box::from_raw(slice) }.into_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn vec_casts_keep_the_allocation() {
+        let words = vec![1u32, 2, 3, 4];
+        let ptr = words.as_ptr() as usize;
+
+        let signed: Vec<i32> = cast_vec(words);
+        assert_eq!(signed, [1, 2, 3, 4]);
+        assert_eq!(signed.as_ptr() as usize, ptr);
+
+        let pairs: Vec<[i32; 2]> = try_cast_vec(signed).unwrap();
+        assert_eq!(pairs, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn vec_cast_mismatches() {
+        let mut odd = Vec::<u16>::with_capacity(4);
+        odd.extend([1, 2, 3]);
+        let err = try_cast_vec::<u16, [u16; 2]>(odd).unwrap_err();
+        assert!(matches!(err, CastError::SizeError(_)));
+        assert_eq!(err.into_src(), [1, 2, 3]);
+
+        let mut spare = Vec::<u16>::with_capacity(3);
+        spare.extend([1, 2]);
+        let err = try_cast_vec::<u16, [u16; 2]>(spare).unwrap_err();
+        assert!(matches!(err, CastError::SizeError(_)));
+        assert_eq!(err.into_src().capacity(), 3);
+
+        let err = bytes_of_vec(vec![1u32]).unwrap_err();
+        assert!(matches!(err, CastError::AlignmentError(_)));
+        assert_eq!(err.into_src(), [1]);
+        assert_eq!(bytes_of_vec(vec![[1u8, 2]]).unwrap(), [1, 2]);
+    }
+
+    #[test]
+    fn owned_from_bytes() {
+        let bytes = [1u8, 0, 0, 0, 2, 0, 0, 0, 0];
+
+        let boxed = box_from_bytes::<u32>(&bytes[1..5]).unwrap();
+        assert_eq!(*boxed, u32::from_ne_bytes([0, 0, 0, 2]));
+        let boxed: Box<i32> = cast_box(boxed);
+        assert_eq!(*boxed, i32::from_ne_bytes([0, 0, 0, 2]));
+
+        let words = vec_from_bytes::<u32>(&bytes[1..]).unwrap();
+        assert_eq!(words.len(), 2);
+        let err = vec_from_bytes::<u32>(&bytes).unwrap_err();
+        assert!(matches!(err, CastError::SizeError(_)));
+        assert_eq!(err.into_src(), bytes);
+        assert!(box_from_bytes::<u32>(&bytes)
+            .unwrap_err()
+            .error()
+            .is_size_bound_failure());
+    }
+
+    #[test]
+    fn zeroed_allocations() {
+        assert_eq!(*zeroed_box::<[u64; 512]>(), [0; 512]);
+        assert_eq!(
+            zeroed_vec::<Option<core::num::NonZeroU8>>(3).unwrap(),
+            [None; 3]
+        );
+        assert!(zeroed_vec::<u64>(usize::MAX)
+            .unwrap_err()
+            .is_integer_overflow());
+    }
+}
@@ -0,0 +1,168 @@
+use super::{AlignmentError, CastError, SizeError, ValidityError};
+use crate::traits::{CheckedBitPattern, InteriorImmutable, Pod};
+use core::slice;
+
+/// A `T` split off the front of some bytes, or the bytes that could not hold it.
+type PrefixResult<'a, T> = Result<(&'a T, &'a [u8]), CastError<&'a [u8], T>>;
+
+/// Checks that the input at `addr` has a suitable length and alignment for a
+/// `T`, reporting the size before the alignment.
+#[inline(always)]
+fn check_layout<Src, Dst: ?Sized, T>(
+    src: Src,
+    addr: *const u8,
+    len_ok: bool,
+) -> Result<Src, CastError<Src, Dst>> {
+    const {
+        assert!(size_of::<T>() > 0, "cannot cast between ZSTs");
+    }
+
+    if !len_ok {
+        return Err(SizeError::new(src).into());
+    }
+    if (addr as usize) % align_of::<T>() != 0 {
+        return Err(AlignmentError::new(src).into());
+    }
+    Ok(src)
+}
+
+/// Like [`ref_from_bytes`](super::ref_from_bytes), but hands the bytes back
+/// inside a [`CastError`] on failure.
+///
+/// # Errors
+///
+/// Returns a [`SizeError`] if the length is not exactly `size_of::<T>()`, or
+/// an [`AlignmentError`] if the bytes are not aligned for `T`.
+#[inline(always)]
+pub fn ref_from_bytes_typed<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<&T, CastError<&[u8], T>> {
+    let bytes = check_layout::<_, T, T>(bytes, bytes.as_ptr(), bytes.len() == size_of::<T>())?;
+    Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+}
+
+/// Like [`mut_from_bytes`](super::mut_from_bytes), but hands the bytes back
+/// inside a [`CastError`] on failure.
+///
+/// # Errors
+///
+/// Returns a [`SizeError`] if the length is not exactly `size_of::<T>()`, or
+/// an [`AlignmentError`] if the bytes are not aligned for `T`.
+#[inline(always)]
+pub fn mut_from_bytes_typed<T: Pod>(bytes: &mut [u8]) -> Result<&mut T, CastError<&mut [u8], T>> {
+    let (addr, len_ok) = (bytes.as_ptr(), bytes.len() == size_of::<T>());
+    let bytes = check_layout::<_, T, T>(bytes, addr, len_ok)?;
+    Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<T>() })
+}
+
+/// Like [`slice_from_bytes`](super::slice_from_bytes), but hands the bytes
+/// back inside a [`CastError`] on failure.
+///
+/// # Errors
+///
+/// Returns a [`SizeError`] if the length is not a multiple of
+/// `size_of::<T>()`, or an [`AlignmentError`] if the bytes are not aligned for
+/// `T`.
+#[inline(always)]
+pub fn slice_from_bytes_typed<T: Pod + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<&[T], CastError<&[u8], [T]>> {
+    let bytes =
+        check_layout::<_, [T], T>(bytes, bytes.as_ptr(), bytes.len() % size_of::<T>() == 0)?;
+    let len = bytes.len() / size_of::<T>();
+    Ok(unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<T>(), len) })
+}
+
+/// Like [`ref_from_prefix`](super::ref_from_prefix), but hands the bytes back
+/// inside a [`CastError`] on failure so a parser can resume from them.
+///
+/// # Errors
+///
+/// Returns a [`SizeError`] if fewer than `size_of::<T>()` bytes are given, or
+/// an [`AlignmentError`] if the bytes are not aligned for `T`.
+#[inline(always)]
+pub fn ref_from_prefix_typed<T: Pod + InteriorImmutable>(bytes: &[u8]) -> PrefixResult<'_, T> {
+    let bytes = check_layout::<_, T, T>(bytes, bytes.as_ptr(), bytes.len() >= size_of::<T>())?;
+    let (head, rest) = bytes.split_at(size_of::<T>());
+    Ok((unsafe { &*head.as_ptr().cast::<T>() }, rest))
+}
+
+/// Like [`try_ref_from_bytes`](super::try_ref_from_bytes), but hands the bytes
+/// back inside a [`CastError`] on failure.
+///
+/// # Errors
+///
+/// Returns a [`SizeError`] if the length is not exactly `size_of::<T>()`, an
+/// [`AlignmentError`] if the bytes are not aligned for `T`, or a
+/// [`ValidityError`] if the bytes are not a valid `T`.
+#[inline(always)]
+pub fn try_ref_from_bytes_typed<T: CheckedBitPattern + InteriorImmutable>(
+    bytes: &[u8],
+) -> Result<&T, CastError<&[u8], T>> {
+    let bytes = check_layout::<_, T, T>(bytes, bytes.as_ptr(), bytes.len() == size_of::<T>())?;
+    if !T::is_valid_bit_pattern(bytes) {
+        return Err(ValidityError::new(bytes).into());
+    }
+    Ok(unsafe { &*bytes.as_ptr().cast::<T>() })
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::BrinyError;
+    use std::string::ToString;
+
+    #[repr(C, align(4))]
+    struct Buf([u8; 9]);
+
+    #[test]
+    fn failures_hand_back_the_input() {
+        let buf = Buf([1, 0, 0, 0, 2, 0, 0, 0, 3]);
+
+        let err = ref_from_bytes_typed::<u32>(&buf.0).unwrap_err();
+        assert!(matches!(err, CastError::SizeError(_)));
+        assert_eq!(err.into_src(), &buf.0);
+
+        let err = ref_from_prefix_typed::<u32>(&buf.0[1..]).unwrap_err();
+        assert!(matches!(err, CastError::AlignmentError(_)));
+        assert_eq!(BrinyError::from(err), BrinyError::UNALIGNED_ACCESS);
+        assert_eq!(err.src().len(), 8);
+
+        let (value, rest) = ref_from_prefix_typed::<u32>(&buf.0).unwrap();
+        assert_eq!(*value, u32::from_ne_bytes([1, 0, 0, 0]));
+        assert_eq!(rest.len(), 5);
+    }
+
+    #[test]
+    fn mutable_and_slice_casts() {
+        let mut buf = Buf([0; 9]);
+
+        *mut_from_bytes_typed::<u32>(&mut buf.0[..4]).unwrap() = u32::MAX;
+        assert_eq!(buf.0[..4], [0xFF; 4]);
+
+        let err = mut_from_bytes_typed::<u32>(&mut buf.0[1..5]).unwrap_err();
+        let src = err.into_src();
+        src[0] = 7;
+        assert_eq!(buf.0[1], 7);
+
+        assert_eq!(
+            slice_from_bytes_typed::<u16>(&buf.0[4..8]).unwrap().len(),
+            2
+        );
+        let err = slice_from_bytes_typed::<u16>(&buf.0).unwrap_err();
+        assert_eq!(err.error(), BrinyError::SIZE_BOUND_FAILURE);
+    }
+
+    #[test]
+    fn validity_errors() {
+        let err = try_ref_from_bytes_typed::<bool>(&[2]).unwrap_err();
+        assert!(matches!(err, CastError::ValidityError(_)));
+        assert_eq!(
+            err.to_string(),
+            "invalid bitpattern casting `&[u8]` into `bool`"
+        );
+        assert!(*try_ref_from_bytes_typed::<bool>(&[1]).unwrap());
+    }
+}
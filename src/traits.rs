@@ -1,8 +1,21 @@
 //! Traits to abstract common characteristics among types.
+//!
+//! With the `derive` feature enabled, each marker trait can be derived with
+//! layout verification (see the derive macros of the same name).
 
 use core::{cell::{Cell, LazyCell, OnceCell, RefCell, RefMut, UnsafeCell}, marker::PhantomData, mem::{ManuallyDrop, MaybeUninit}, num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping}, pin::Pin, ptr::NonNull, sync::atomic::{AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize}};
 
+#[cfg(feature = "derive")]
+pub use briny_derive::{
+    CheckedBitPattern, InteriorImmutable, Pod, RawConvert, StableLayout, Unaligned,
+};
+
 /// A simple marker trait for types that have a consistent layout in memory.
+///
+/// # Safety
+///
+/// The type must have a defined layout (e.g. `repr(C)` or `repr(transparent)`)
+/// that never changes between compilations.
 pub unsafe trait StableLayout: 'static {}
 
 unsafe impl StableLayout for u8 {}
@@ -20,6 +33,7 @@ unsafe impl StableLayout for isize {}
 unsafe impl StableLayout for f32 {}
 unsafe impl StableLayout for f64 {}
 unsafe impl StableLayout for bool {}
+unsafe impl StableLayout for char {}
 unsafe impl StableLayout for AtomicU8 {}
 unsafe impl StableLayout for AtomicI8 {}
 unsafe impl StableLayout for AtomicU16 {}
@@ -64,6 +78,10 @@ unsafe impl<T: 'static> StableLayout for PhantomData<T> {}
 /// and every other type should implement it's complement. Anything
 /// which either implements both or implements neither can be considered
 /// a logic error (or undefined behavior in the case of the former).
+///
+/// # Safety
+///
+/// The type must not contain any [`UnsafeCell`], directly or indirectly.
 pub unsafe trait InteriorImmutable {}
 
 unsafe impl InteriorImmutable for u8 {}
@@ -81,6 +99,7 @@ unsafe impl InteriorImmutable for usize {}
 unsafe impl InteriorImmutable for f32 {}
 unsafe impl InteriorImmutable for f64 {}
 unsafe impl InteriorImmutable for bool {}
+unsafe impl InteriorImmutable for char {}
 unsafe impl InteriorImmutable for NonZeroU8 {}
 unsafe impl InteriorImmutable for NonZeroI8 {}
 unsafe impl InteriorImmutable for NonZeroU16 {}
@@ -103,6 +122,10 @@ unsafe impl<T> InteriorImmutable for PhantomData<T> {}
 /// Any type that is interiorly mutable.
 ///
 /// This is the complement of [`InteriorImmutable`] as described.
+///
+/// # Safety
+///
+/// The type must not also implement [`InteriorImmutable`].
 pub unsafe trait Writable {}
 
 unsafe impl Writable for AtomicU8 {}
@@ -182,6 +205,7 @@ unsafe impl RawConvert for usize {}
 unsafe impl RawConvert for f32 {}
 unsafe impl RawConvert for f64 {}
 unsafe impl RawConvert for bool {}
+unsafe impl RawConvert for char {}
 unsafe impl RawConvert for NonZeroU8 {}
 unsafe impl RawConvert for NonZeroI8 {}
 unsafe impl RawConvert for NonZeroU16 {}
@@ -245,6 +269,104 @@ unsafe impl<T: Unaligned> Unaligned for ManuallyDrop<T> {}
 unsafe impl<T: Unaligned> Unaligned for Wrapping<T> {}
 unsafe impl<T> Unaligned for PhantomData<T> {}
 
+/// Trait for types that can be validated from raw bytes before a cast.
+///
+/// Unlike [`Pod`], not every bitpattern has to be valid: a `bool` only accepts
+/// `0` and `1`, a `NonZeroU32` rejects zero, and a `repr(u8)` enum only accepts
+/// its declared discriminants.
+///
+/// # Safety
+///
+/// [`CheckedBitPattern::is_valid_bit_pattern`] must only return `true` if the
+/// bytes form a valid value of `Self`. Returning `true` for an invalid value is
+/// undefined behavior once the bytes are reinterpreted.
+pub unsafe trait CheckedBitPattern: StableLayout + RawConvert {
+    /// Checks whether `bytes` hold a valid value of `Self`.
+    ///
+    /// Callers always pass exactly `size_of::<Self>()` bytes, but they are not
+    /// guaranteed to be aligned.
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool;
+}
+
+macro_rules! checked_any_bit_pattern {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl CheckedBitPattern for $t {
+                #[inline(always)]
+                fn is_valid_bit_pattern(_bytes: &[u8]) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! checked_non_zero {
+    ($($t:ty),* $(,)?) => {
+        $(
+            unsafe impl CheckedBitPattern for $t {
+                #[inline(always)]
+                fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+                    bytes.iter().any(|&byte| byte != 0)
+                }
+            }
+        )*
+    };
+}
+
+checked_any_bit_pattern!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64);
+checked_non_zero!(
+    NonZeroU8,
+    NonZeroI8,
+    NonZeroU16,
+    NonZeroI16,
+    NonZeroU32,
+    NonZeroI32,
+    NonZeroU64,
+    NonZeroI64,
+    NonZeroU128,
+    NonZeroI128,
+    NonZeroUsize,
+    NonZeroIsize,
+);
+
+unsafe impl CheckedBitPattern for bool {
+    #[inline(always)]
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        bytes[0] <= 1
+    }
+}
+
+unsafe impl CheckedBitPattern for char {
+    #[inline(always)]
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        let mut raw = [0u8; 4];
+        raw.copy_from_slice(bytes);
+        Self::from_u32(u32::from_ne_bytes(raw)).is_some()
+    }
+}
+
+unsafe impl<T: CheckedBitPattern, const N: usize> CheckedBitPattern for [T; N] {
+    #[inline(always)]
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        size_of::<T>() == 0 || bytes.chunks_exact(size_of::<T>()).all(T::is_valid_bit_pattern)
+    }
+}
+
+unsafe impl<T: CheckedBitPattern> CheckedBitPattern for ManuallyDrop<T> {
+    #[inline(always)]
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        T::is_valid_bit_pattern(bytes)
+    }
+}
+
+unsafe impl<T: CheckedBitPattern> CheckedBitPattern for Wrapping<T> {
+    #[inline(always)]
+    fn is_valid_bit_pattern(bytes: &[u8]) -> bool {
+        T::is_valid_bit_pattern(bytes)
+    }
+}
+
 /// POD marker trait for *Plain Old Data*.
 ///
 /// # Safety
@@ -280,3 +402,75 @@ unsafe impl<T: Pod> Pod for Wrapping<T> {}
 unsafe impl<T: 'static> Pod for PhantomData<T> {}
 unsafe impl<T: 'static> Pod for *const T {}
 unsafe impl<T: 'static> Pod for *mut T {}
+
+/// Trait for types whose all-zero bitpattern is a valid value.
+///
+/// Every [`Pod`] type is zeroable, as is `Option<T>` for any [`NonNullable`]
+/// `T` thanks to the null pointer optimization (zero is `None`).
+///
+/// [`Zeroable::zero_in_place`] initializes a value where it already lives,
+/// which avoids building large values on the stack.
+///
+/// # Safety
+///
+/// Writing zero to every byte of `Self` must produce a valid value.
+pub unsafe trait Zeroable: Sized {
+    /// Returns a value with every byte set to zero.
+    #[inline(always)]
+    #[must_use]
+    fn zeroed() -> Self {
+        crate::raw::zeroed()
+    }
+
+    /// Zeroes `slot` in place and returns it as initialized.
+    #[inline(always)]
+    fn zero_in_place(slot: &mut MaybeUninit<Self>) -> &mut Self {
+        crate::raw::zero_in_place(slot)
+    }
+
+    /// Overwrites every byte of `self` with zero without dropping the old value.
+    #[inline(always)]
+    fn zero(&mut self) {
+        crate::raw::zero(self);
+    }
+}
+
+unsafe impl<T: Pod> Zeroable for T {}
+unsafe impl<T: NonNullable> Zeroable for Option<T> {}
+unsafe impl Zeroable for bool {}
+unsafe impl Zeroable for char {}
+unsafe impl Zeroable for AtomicU8 {}
+unsafe impl Zeroable for AtomicI8 {}
+unsafe impl Zeroable for AtomicU16 {}
+unsafe impl Zeroable for AtomicI16 {}
+unsafe impl Zeroable for AtomicU32 {}
+unsafe impl Zeroable for AtomicI32 {}
+unsafe impl Zeroable for AtomicU64 {}
+unsafe impl Zeroable for AtomicI64 {}
+unsafe impl Zeroable for AtomicUsize {}
+unsafe impl Zeroable for AtomicIsize {}
+unsafe impl Zeroable for AtomicBool {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zeroable_values() {
+        assert_eq!(<[u32; 4]>::zeroed(), [0; 4]);
+        assert_eq!(Option::<NonZeroU32>::zeroed(), None);
+        assert_eq!(Option::<NonNull<u8>>::zeroed(), None);
+        assert!(!bool::zeroed());
+
+        let mut value = [0xFFu8; 16];
+        value.zero();
+        assert_eq!(value, [0; 16]);
+    }
+
+    #[test]
+    fn zero_in_place_initializes() {
+        let mut slot = MaybeUninit::<[u64; 512]>::uninit();
+        let value = <[u64; 512]>::zero_in_place(&mut slot);
+        assert!(value.iter().all(|&word| word == 0));
+    }
+}
@@ -39,6 +39,7 @@ pub const fn abort(msg: &str) -> ! {
         }
     }
 
+    #[allow(clippy::never_loop)] // the panic never returns, but the loop documents intent
     loop {
         let _abort = PanicOnDrop;
         panic!("{}", msg); // cause panic while `PanicOnDrop` is live
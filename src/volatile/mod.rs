@@ -0,0 +1,292 @@
+//! Volatile memory access and memory-mapped register blocks.
+//!
+//! [`VolatileCell`] wraps a [`Pod`] value whose every read and write is
+//! volatile, so the compiler never caches, merges or elides an access. The
+//! register types [`ReadOnly`], [`WriteOnly`] and [`ReadWrite`] build on it and
+//! only expose the accesses their hardware allows. Their contents change behind
+//! shared references, so all of them are [`Writable`] and never
+//! [`InteriorImmutable`](crate::traits::InteriorImmutable).
+//!
+//! Peripherals are described with [`register_block!`](crate::register_block),
+//! which checks every register offset at compile time:
+//!
+//! ```
+//! use briny::volatile::{ReadOnly, ReadWrite, WriteOnly};
+//!
+//! briny::register_block! {
+//!     /// A serial port.
+//!     pub struct Uart {
+//!         0x00 => pub data: ReadWrite<u32>,
+//!         0x04 => pub status: ReadOnly<u32>,
+//!         0x08 => _reserved: [u32; 2],
+//!         0x10 => pub control: WriteOnly<u32>,
+//!     }
+//! }
+//!
+//! # let mut mmio = [0u32; 5];
+//! # let base = mmio.as_mut_ptr();
+//! let uart = unsafe { &*base.cast::<Uart>() };
+//! uart.control.write(0b11);
+//! uart.data.modify(|data| data | 0x80);
+//! assert_eq!(uart.status.read(), 0);
+//! ```
+
+use crate::{
+    raw,
+    traits::{Pod, StableLayout, Writable},
+};
+use core::{cell::UnsafeCell, fmt, ops};
+
+/// A [`Pod`] value that is only ever accessed with volatile reads and writes.
+#[repr(transparent)]
+pub struct VolatileCell<T>(UnsafeCell<T>);
+
+impl<T: Pod> VolatileCell<T> {
+    /// Creates a cell holding `value`.
+    #[inline(always)]
+    pub const fn new(value: T) -> Self {
+        Self(UnsafeCell::new(value))
+    }
+
+    /// Reads the value with a volatile load.
+    #[inline(always)]
+    pub fn get(&self) -> T {
+        raw::read_volatile(&self.0)
+    }
+
+    /// Writes `value` with a volatile store.
+    #[inline(always)]
+    pub fn set(&self, value: T) {
+        raw::write_volatile(&self.0, value);
+    }
+
+    /// Reads the value, transforms it with `f` and writes the result back.
+    ///
+    /// This is two separate volatile accesses, not an atomic operation.
+    #[inline(always)]
+    pub fn update(&self, f: impl FnOnce(T) -> T) {
+        self.set(f(self.get()));
+    }
+
+    /// A raw pointer to the value.
+    #[inline(always)]
+    pub const fn as_ptr(&self) -> *mut T {
+        self.0.get()
+    }
+
+    /// Consumes the cell, returning the value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.0.into_inner()
+    }
+}
+
+impl<T: Pod + Default> Default for VolatileCell<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Pod + fmt::Debug> fmt::Debug for VolatileCell<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VolatileCell").field(&self.get()).finish()
+    }
+}
+
+unsafe impl<T: StableLayout> StableLayout for VolatileCell<T> {}
+unsafe impl<T> Writable for VolatileCell<T> {}
+
+macro_rules! register {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        pub struct $name<T>(VolatileCell<T>);
+
+        impl<T: Pod> $name<T> {
+            /// Creates a register holding `value`, e.g. to simulate hardware.
+            #[inline(always)]
+            pub const fn new(value: T) -> Self {
+                Self(VolatileCell::new(value))
+            }
+
+            /// A raw pointer to the register.
+            #[inline(always)]
+            pub const fn as_ptr(&self) -> *mut T {
+                self.0.as_ptr()
+            }
+        }
+
+        unsafe impl<T: StableLayout> StableLayout for $name<T> {}
+        unsafe impl<T> Writable for $name<T> {}
+    };
+}
+
+register!(
+    /// A register that can only be read.
+    ReadOnly
+);
+
+register!(
+    /// A register that can only be written.
+    WriteOnly
+);
+
+register!(
+    /// A register that can be read and written.
+    ReadWrite
+);
+
+impl<T: Pod> ReadOnly<T> {
+    /// Reads the register.
+    #[inline(always)]
+    pub fn read(&self) -> T {
+        self.0.get()
+    }
+}
+
+impl<T: Pod> WriteOnly<T> {
+    /// Writes `value` to the register.
+    #[inline(always)]
+    pub fn write(&self, value: T) {
+        self.0.set(value);
+    }
+}
+
+impl<T: Pod> ReadWrite<T> {
+    /// Reads the register.
+    #[inline(always)]
+    pub fn read(&self) -> T {
+        self.0.get()
+    }
+
+    /// Writes `value` to the register.
+    #[inline(always)]
+    pub fn write(&self, value: T) {
+        self.0.set(value);
+    }
+
+    /// Reads the register, transforms the value with `f` and writes it back.
+    ///
+    /// This is two separate volatile accesses, not an atomic operation.
+    #[inline(always)]
+    pub fn modify(&self, f: impl FnOnce(T) -> T) {
+        self.0.update(f);
+    }
+}
+
+impl<T> ReadWrite<T>
+where
+    T: Pod
+        + ops::BitOr<Output = T>
+        + ops::BitAnd<Output = T>
+        + ops::BitXor<Output = T>
+        + ops::Not<Output = T>,
+{
+    /// Sets every bit of `mask` with a read-modify-write.
+    #[inline(always)]
+    pub fn set_bits(&self, mask: T) {
+        self.modify(|value| value | mask);
+    }
+
+    /// Clears every bit of `mask` with a read-modify-write.
+    #[inline(always)]
+    pub fn clear_bits(&self, mask: T) {
+        self.modify(|value| value & !mask);
+    }
+
+    /// Flips every bit of `mask` with a read-modify-write.
+    #[inline(always)]
+    pub fn toggle_bits(&self, mask: T) {
+        self.modify(|value| value ^ mask);
+    }
+}
+
+/// Declares a `repr(C)` block of registers and asserts the offset of each one
+/// at compile time.
+///
+/// Each field is written as `offset => visibility name: Type`. Gaps between
+/// registers must be filled with explicit reserved fields, otherwise the
+/// offsets do not line up and compilation fails:
+///
+/// ```compile_fail
+/// use briny::volatile::ReadWrite;
+///
+/// briny::register_block! {
+///     struct Gpio {
+///         0x0 => mode: ReadWrite<u32>,
+///         0x8 => output: ReadWrite<u32>,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! register_block {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($offset:literal => $field_vis:vis $field:ident : $ty:ty),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(C)]
+        $vis struct $name {
+            $($field_vis $field: $ty,)*
+        }
+
+        const _: () = {
+            $(
+                ::core::assert!(
+                    ::core::mem::offset_of!($name, $field) == $offset,
+                    ::core::concat!(
+                        "register `",
+                        ::core::stringify!($field),
+                        "` is not at offset ",
+                        ::core::stringify!($offset),
+                    ),
+                );
+            )*
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::register_block! {
+        struct Timer {
+            0x0 => count: ReadOnly<u32>,
+            0x4 => reload: WriteOnly<u32>,
+            0x8 => control: ReadWrite<u16>,
+            0xA => _reserved: u16,
+            0xC => flags: ReadWrite<u8>,
+        }
+    }
+
+    #[test]
+    fn cell_reads_and_writes() {
+        let cell = VolatileCell::new(5u64);
+        cell.set(6);
+        cell.update(|value| value * 2);
+        assert_eq!(cell.get(), 12);
+        assert_eq!(cell.into_inner(), 12);
+    }
+
+    #[test]
+    fn registers_over_raw_memory() {
+        let mut mmio = [0u32; 4];
+        mmio[0] = 42;
+
+        let timer = unsafe { &*mmio.as_mut_ptr().cast::<Timer>() };
+        assert_eq!(timer.count.read(), 42);
+        timer.reload.write(1000);
+        timer.control.write(0b0101);
+        timer.control.set_bits(0b1000);
+        timer.control.clear_bits(0b0001);
+        timer.control.toggle_bits(0b0010);
+        timer.flags.modify(|flags| flags | 0x80);
+        assert_eq!(timer.control.read(), 0b1110);
+
+        assert_eq!(mmio[1], 1000);
+        assert_eq!(size_of::<Timer>(), 16);
+    }
+}
@@ -0,0 +1,130 @@
+//! Reliable clearing of sensitive values.
+//!
+//! Plain assignments of zero to memory that is never read again are routinely
+//! removed by the optimizer. [`Zeroize`] instead clears every byte with a
+//! volatile write followed by a compiler fence, so the clearing is always
+//! emitted and not reordered past later code. [`Secret`] applies it on drop.
+
+use crate::{raw, traits::Pod};
+use core::{
+    fmt,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+/// A value whose memory can be reliably overwritten with zeroes.
+pub trait Zeroize {
+    /// Overwrites every byte of `self` with zero.
+    fn zeroize(&mut self);
+}
+
+impl<T: Pod> Zeroize for T {
+    #[inline]
+    fn zeroize(&mut self) {
+        zeroize_bytes(raw::to_bytes_mut(self));
+    }
+}
+
+/// Overwrites every byte of a slice of [`Pod`] values with zero.
+#[inline]
+pub fn zeroize_slice<T: Pod>(values: &mut [T]) {
+    zeroize_bytes(raw::slice_to_bytes_mut(values));
+}
+
+#[inline(never)]
+fn zeroize_bytes(bytes: &mut [u8]) {
+    raw::zero_volatile(bytes);
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// A [`Pod`] value that is zeroized when dropped.
+///
+/// The value is hidden from [`Debug`](fmt::Debug) output and only reachable
+/// through an explicit [`Secret::expose`] or [`Secret::expose_mut`] borrow.
+/// It is not [`Clone`], so no stray copy outlives the secret.
+///
+/// ```
+/// use briny::zeroize::Secret;
+///
+/// let key = Secret::new([0x42u8; 32]);
+/// assert_eq!(key.expose()[0], 0x42);
+/// assert_eq!(format!("{key:?}"), "Secret([REDACTED])");
+/// ```
+pub struct Secret<T: Pod>(T);
+
+impl<T: Pod> Secret<T> {
+    /// Takes ownership of `value`.
+    ///
+    /// Copies of `value` made before this call, such as the one passed in, are
+    /// not cleared.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Borrows the secret value.
+    #[inline]
+    pub const fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Mutably borrows the secret value, e.g. to fill it in place.
+    #[inline]
+    pub const fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Pod> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Pod> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Pod> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn zeroize_values_and_slices() {
+        let mut key = [0xAAu8; 16];
+        key.zeroize();
+        assert_eq!(key, [0; 16]);
+
+        let mut words = [u64::MAX; 4];
+        zeroize_slice(&mut words[1..3]);
+        assert_eq!(words, [u64::MAX, 0, 0, u64::MAX]);
+    }
+
+    #[test]
+    fn secret_clears_on_drop() {
+        let mut secret = Secret::new([7u32; 8]);
+        secret.expose_mut()[0] = 9;
+        assert_eq!(secret.expose()[..2], [9, 7]);
+
+        // the same wipe `Drop` runs, observed while the secret is still alive
+        secret.expose_mut().zeroize();
+        assert!(raw::to_bytes(secret.expose()).iter().all(|&byte| byte == 0));
+        drop(secret);
+    }
+
+    #[test]
+    fn secret_is_redacted() {
+        let secret = Secret::from(0x1234_5678u32);
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+    }
+}
@@ -1,5 +1,9 @@
+// `Pair` keeps the plain `repr(packed)` it was written with.
+#![allow(clippy::repr_packed_without_abi)]
+
+use briny::align::{Align4, AlignedBytes};
 use briny::raw::{cast, from_bytes, slice_from_bytes, to_bytes};
-use briny::traits::{Pod, RawConvert, StableLayout};
+use briny::traits::{InteriorImmutable, Pod, RawConvert, StableLayout};
 
 #[test]
 fn to_bytes_roundtrip_fuzz() {
@@ -49,16 +53,14 @@ fn slice_from_bytes_misaligned_fuzz() {
     unsafe impl StableLayout for Word {}
     unsafe impl RawConvert for Word {}
     unsafe impl Pod for Word {}
-
-    #[repr(align(4))]
-    struct Align4([u8; 64]);
+    unsafe impl InteriorImmutable for Word {}
 
     let alignment = align_of::<Word>();
     let size = size_of::<Word>() * 10;
 
-    let a = Align4([0u8; 64]);
+    let a = AlignedBytes::<64, Align4>::ZERO;
     for offset in 0..=16 {
-        let slice = &a.0[offset..offset + size];
+        let slice = &a[offset..offset + size];
         let result = slice_from_bytes::<Word>(slice);
 
         if offset % alignment == 0 {